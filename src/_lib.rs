@@ -1,5 +1,17 @@
 // TODO: main crate docs.
 #![warn(unsafe_op_in_unsafe_fn)]
+#![cfg_attr(feature = "nightly",
+    feature(
+        allow_internal_unstable,
+        arbitrary_self_types,
+        coerce_unsized,
+        dispatch_from_dyn,
+        pin_coerce_unsized_trait,
+        set_ptr_value,
+        unsize,
+    ),
+    allow(internal_features),
+)]
 
 #[macro_use]
 extern crate extension_traits;
@@ -9,12 +21,14 @@ extern crate extension_traits;
 extern crate macro_rules_attribute;
 
 #[cfg(test)]
-extern crate self as own_ref;
+pub extern crate self as own_ref;
 
 #[macro_use]
 mod utils;
 
 pub use self::{
+    dyn_slot::{DynSlot, dyn_slot},
+    inline_dyn::InlineDyn,
     own::OwnRef,
     slot::{Slot, slot, slots},
 };
@@ -26,6 +40,10 @@ use self::{
 
 mod arities;
 
+mod dyn_slot;
+
+mod inline_dyn;
+
 mod own;
 
 pub
@@ -50,8 +68,10 @@ mod prelude {
         crate::{
             OwnRef,
             own_ref,
+            pinned_own_ref,
+            pin::TupleSlots as _,
             slot::{slot, slots},
-            traits::{FnOwn, MaybeUninitExt as _},
+            traits::{FnOwn, MaybeUninitExt as _, TupleSlots as _},
         },
         module::pin,
     };
@@ -83,9 +103,13 @@ mod ඞ {
         crate::{
             own::{
                 HackMD,
+                Raw,
             },
+            pin::projection::DropInPlace,
             token::Unsafe,
+            traits::coerce_own::ඞCoerceOwnFrom,
         },
+        ::paste::paste,
     };
 }
 
@@ -0,0 +1,239 @@
+//! Type-erased, fixed-capacity [`OwnRef`] backing storage.
+
+use super::*;
+use ::core::cell::UnsafeCell;
+
+/// Same as [`slot()`], but for a type-erased [`DynSlot`].
+///
+/// The `SIZE` and `ALIGN` are usually inferred from the signature of the
+/// function being fed the slot:
+///
+/// ```rust
+/// use ::own_ref::{prelude::*, DynSlot, dyn_slot};
+///
+/// fn callee(out: &mut DynSlot<64, 8>) {
+///     // …
+/// }
+///
+/// callee(&mut dyn_slot());
+/// ```
+#[inline(always)]
+pub
+const
+fn dyn_slot<const SIZE: usize, const ALIGN: usize>()
+  -> DynSlot<SIZE, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    DynSlot::VACANT
+}
+
+/// Type-erased [`Slot`]: `SIZE` bytes of storage, aligned to `ALIGN`, capable
+/// of [`.holding()`][DynSlot::holding()] a value of _any_ type `T` which fits
+/// in it.
+///
+/// Whereas a <code>[Slot]\<T\></code> forces the caller, _i.e._, the provider
+/// of the backing storage, to name the very type `T` to be held, a
+/// [`DynSlot`] only involves a _capacity_, leaving the choice of the actual
+/// type to the callee.
+///
+/// This makes it the missing piece to return unsized values, such as
+/// <code>[OwnRef]\<\'\_, dyn Trait\></code>s, out of functions, without `Box`es:
+///
+/// ```rust
+/// use ::own_ref::{prelude::*, DynSlot, dyn_slot, unsize};
+///
+/// fn greeter<'o>(
+///     out: &'o mut DynSlot<64, 8>,
+///     loud: bool,
+/// ) -> OwnRef<'o, dyn FnOwn<(), Ret = String>>
+/// {
+///     let name = String::from("world");
+///     if loud {
+///         unsize!(
+///             out .holding(move || format!("HELLO, {}!", name.to_uppercase()))
+///                 .ok()
+///                 .expect("fits")
+///         )
+///     } else {
+///         unsize!(
+///             out .holding(move || format!("hello, {name}"))
+///                 .ok()
+///                 .expect("fits")
+///         )
+///     }
+/// }
+///
+/// let storage = &mut dyn_slot();
+/// let f = greeter(storage, true);
+/// assert_eq!(f.call_ownref_0(), "HELLO, WORLD!");
+/// ```
+///
+/// ## Capacity checks
+///
+/// Since `T` is chosen "late", there is no guarantee that it will fit in the
+/// given storage, which is why [`.holding()`][DynSlot::holding()] is fallible,
+/// handing the `value` back on mismatch.
+///
+/// For a concrete `T`, the check boils down to a constant, so it costs nothing
+/// at runtime; and if you wish to have it be a hard compile-time error
+/// instead, you can `const`-assert [`DynSlot::fits()`]:
+///
+/// ```rust
+/// use ::own_ref::DynSlot;
+///
+/// const _: () = assert!(DynSlot::<64, 8>::fits::<[u64; 8]>());
+/// ```
+///
+/// ```rust ,compile_fail
+/// use ::own_ref::DynSlot;
+///
+/// const _: () = assert!(DynSlot::<64, 8>::fits::<[u64; 9]>());
+/// ```
+///
+/// ## Valid `ALIGN`ments
+///
+/// `ALIGN` must be a power of two, up to `4096`, as expressed by the
+/// <code>[Alignment]\<ALIGN\> : [ValidAlignment]</code> bound.
+///
+/// [Alignment]: crate::traits::Alignment
+/// [ValidAlignment]: crate::traits::ValidAlignment
+#[repr(C)]
+pub
+struct DynSlot<const SIZE: usize, const ALIGN: usize>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    _align: [<Alignment<ALIGN> as ValidAlignment>::Archetype; 0],
+    // `UnsafeCell`, since the `T` held in here may feature interior
+    // mutability, even when accessed through a `&DynSlot` (_e.g._, a
+    // `Cell`-capturing closure behind a `&InlineDyn<dyn Fn()>`).
+    bytes: UnsafeCell<MU<[u8; SIZE]>>,
+}
+
+// The `UnsafeCell` is only there for the sake of the held `T`, which the
+// `DynSlot` API never lets the `&DynSlot` holder access.
+unsafe
+impl<const SIZE: usize, const ALIGN: usize> Sync for DynSlot<SIZE, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{}
+
+impl<const SIZE: usize, const ALIGN: usize> DynSlot<SIZE, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    /// Same as [`dyn_slot()`]. See [`Slot::VACANT`] for more info.
+    // (meant to be copied into fresh storage, hence the interior mutability
+    // being fine.)
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub
+    const VACANT: Self = Self {
+        _align: [],
+        bytes: UnsafeCell::new(MU::uninit()),
+    };
+
+    /// Whether a value of type `T` fits in this [`DynSlot`].
+    pub
+    const
+    fn fits<T>()
+      -> bool
+    {
+        Self::fits_layout(
+            ::core::mem::size_of::<T>(),
+            ::core::mem::align_of::<T>(),
+        )
+    }
+
+    pub(in crate)
+    const
+    fn fits_layout(size: usize, align: usize)
+      -> bool
+    {
+        size <= SIZE && align <= ALIGN
+    }
+
+    pub(in crate)
+    fn as_ptr(&self)
+      -> *mut u8
+    {
+        self.bytes.get().cast()
+    }
+
+    pub(in crate)
+    fn as_mut_ptr(&mut self)
+      -> *mut u8
+    {
+        self.bytes.get_mut().as_mut_ptr().cast()
+    }
+
+    /// Same as [`Slot::holding()`], but for it being fallible since `T` may
+    /// not [fit][Self::fits()] in this storage, in which case the `value` is
+    /// handed back.
+    #[inline]
+    pub
+    fn holding<'slot, T>(self: &'slot mut DynSlot<SIZE, ALIGN>, value: T)
+      -> Result<OwnRef<'slot, T>, T>
+    {
+        if Self::fits::<T>().not() {
+            return Err(value);
+        }
+        let ptr: *mut T = self.as_mut_ptr().cast();
+        unsafe {
+            // Safety: we have just checked the size and alignment of `T`
+            // against those of our backing storage, which we have exclusive
+            // access to for `'slot`.
+            ptr.write(value);
+            Ok(OwnRef::from_raw(ptr.cast(), []))
+        }
+    }
+}
+
+/// Type-level representation of an `ALIGN`ment, to be used alongside the
+/// [`ValidAlignment`] bound.
+pub
+struct Alignment<const ALIGN: usize>;
+
+/// Implemented for the valid [`Alignment`]s of a [`DynSlot`], _i.e._, the
+/// powers of two up to `4096`.
+pub
+trait ValidAlignment : sealed::Sealed {
+    /// A zero-sized type with said alignment.
+    #[doc(hidden)] /** Not part of the public API */
+    type Archetype;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impls {(
+    $( $ALIGN:literal => $Archetype:ident ),* $(,)?
+) => (
+    $(
+        #[doc(hidden)] /** Not part of the public API */
+        #[repr(align($ALIGN))]
+        pub
+        struct $Archetype;
+
+        impl sealed::Sealed for Alignment<$ALIGN> {}
+
+        impl ValidAlignment for Alignment<$ALIGN> {
+            type Archetype = $Archetype;
+        }
+    )*
+)} impls! {
+    1 => Align1,
+    2 => Align2,
+    4 => Align4,
+    8 => Align8,
+    16 => Align16,
+    32 => Align32,
+    64 => Align64,
+    128 => Align128,
+    256 => Align256,
+    512 => Align512,
+    1024 => Align1024,
+    2048 => Align2048,
+    4096 => Align4096,
+}
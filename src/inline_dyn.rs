@@ -0,0 +1,302 @@
+//! Owning, movable, fixed-capacity storage for (potentially) unsized values.
+
+use super::*;
+use ::core::ptr::NonNull;
+use crate::traits::{Alignment, ValidAlignment};
+
+/// An owned `T`, stored inline (no heap allocation), within a
+/// [`DynSlot`]`<N, ALIGN>`.
+///
+/// Think of it as a `Box<T>` which happens to be using "local" storage rather
+/// than the heap, with a fixed capacity of `N` bytes.
+///
+/// Contrary to an <code>[OwnRef]\<\'slot, T\></code>, it does not borrow its
+/// backing storage from anywhere, so it can be freely moved around, _e.g._,
+/// returned from a function, much like a `Box<dyn Trait>` would:
+///
+/// ```rust
+/// use ::own_ref::{prelude::*, InlineDyn};
+///
+/// fn counter(start: usize)
+///   -> InlineDyn<dyn FnMut() -> usize, 16>
+/// {
+///     let mut count = start;
+///     InlineDyn::from_own_ref(own_ref!(move || { count += 1; count }))
+///         .ok()
+///         .expect("fits")
+/// }
+///
+/// let mut c = counter(41);
+/// assert_eq!(c(), 42);
+/// assert_eq!(c(), 43);
+/// ```
+///
+/// ## Construction
+///
+///   - <code>InlineDyn::[new]\(value\)</code>, for a `Sized` `T`;
+///
+///   - <code>InlineDyn::[from_own_ref]\([own_ref!]\(value\)\)</code>, which
+///     moves the bytes of the value out of the given [`OwnRef`], and which is
+///     thus able to take advantage of [`own_ref!`]'s built-in unsizing.
+///
+/// [new]: InlineDyn::new()
+/// [from_own_ref]: InlineDyn::from_own_ref()
+///
+/// In both cases, the value may not fit in the `N` bytes (or `ALIGN`ment) of
+/// this storage, in which case it is handed back.
+///
+/// ## `FnOwn` & co.
+///
+/// Since <code>[InlineDyn]\<T, …\></code> [derefs][core::ops::Deref] to `T`,
+/// it can be used as a `T` by reference, _e.g._, for `dyn FnMut`. But it can
+/// also lend its value _by ownership_, in [`OwnRef`] form, through
+/// [`.take_own_ref()`][InlineDyn::take_own_ref()], so as to be able to
+/// use it with the `self`-consuming APIs of [`FnOwn`], for instance:
+///
+/// ```rust
+/// use ::own_ref::{prelude::*, InlineDyn};
+///
+/// fn greeter(name: String)
+///   -> InlineDyn<dyn FnOwn<(), Ret = String>, 32>
+/// {
+///     InlineDyn::from_own_ref(own_ref!(move || format!("hello, {name}")))
+///         .ok()
+///         .expect("fits")
+/// }
+///
+/// let mut f = greeter("world".into());
+/// let f: OwnRef<'_, dyn FnOwn<(), Ret = String>> = f.take_own_ref().unwrap();
+/// assert_eq!(f.call_ownref_0(), "hello, world");
+/// ```
+pub
+struct InlineDyn<T : ?Sized, const N: usize, const ALIGN: usize = 16>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    storage: DynSlot<N, ALIGN>,
+
+    /// `None` when vacant.
+    ///
+    /// Otherwise, only the metadata of this pointer is meaningful, since its
+    /// address part gets stale as soon as `Self` is moved: it is to be
+    /// reattached to `storage` through `utils::with_data_ptr()` (which, on
+    /// stable Rust, relies on, and asserts, the data pointer being laid out
+    /// first within wide pointers).
+    ptr: Option<NonNull<T>>,
+
+    /// We own a `T`.
+    _owned: PD<T>,
+}
+
+impl<T, const N: usize, const ALIGN: usize> InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    /// Moves the `value` into inline storage, if it fits.
+    pub
+    fn new(value: T)
+      -> Result<Self, T>
+    {
+        let mut storage = DynSlot::VACANT;
+        let own_ref = storage.holding(value)?;
+        let (ptr, _) = OwnRef::into_raw(own_ref);
+        Ok(Self {
+            storage,
+            ptr: NonNull::new(ptr.cast()),
+            _owned: PD,
+        })
+    }
+}
+
+impl<T, const N: usize, const ALIGN: usize> InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    /// Performs an [`Unsize`][::core::marker::Unsize] coërcion of the owned
+    /// `T`, _e.g._, into a `dyn Trait`, even in generic code.
+    ///
+    /// Same as [`OwnRef::coerce()`] (see [`CoerceOwn`] for more info), but
+    /// for an [`InlineDyn`]:
+    ///
+    /// ```rust
+    /// use ::own_ref::{prelude::*, InlineDyn};
+    ///
+    /// fn erase<'f, F : FnOwn<(), Ret = ()> + 'f>(f: F)
+    ///   -> InlineDyn<dyn FnOwn<(), Ret = ()> + 'f, 16>
+    /// {
+    ///     InlineDyn::new(f).ok().expect("fits").coerce()
+    /// }
+    ///
+    /// let mut f = erase(|| println!("hi"));
+    /// f.take_own_ref().unwrap().call_ownref_0();
+    /// ```
+    ///
+    /// [`CoerceOwn`]: crate::traits::CoerceOwn
+    pub
+    fn coerce<Dyn : ?Sized>(self)
+      -> InlineDyn<Dyn, N, ALIGN>
+    where
+        T : crate::traits::CoerceOwn<Dyn>,
+    {
+        let this = ::core::mem::ManuallyDrop::new(self);
+        let ptr = this.ptr.map(|ptr| unsafe {
+            // Safety: an unsizing coërcion of a non-null pointer, since
+            // `CoerceOwn` is sealed, with its only impl delegating to the
+            // `unsafe` `ඞCoerceOwnFrom` trait (_c.f._ `OwnRef::coerce()`).
+            NonNull::new_unchecked(T::ඞcoerce(ptr.as_ptr()))
+        });
+        InlineDyn {
+            storage: unsafe {
+                // Safety: `this` is never to be used (nor dropped) again.
+                ::core::ptr::read(&this.storage)
+            },
+            ptr,
+            _owned: PD,
+        }
+    }
+}
+
+impl<T : ?Sized, const N: usize, const ALIGN: usize> InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    /// Moves the value owned by the given [`OwnRef`] into inline storage, if
+    /// it fits.
+    pub
+    fn from_own_ref(o: OwnRef<'_, T>)
+      -> Result<Self, OwnRef<'_, T>>
+    {
+        let mut storage = DynSlot::VACANT;
+        let (ptr, _) = OwnRef::into_raw(o.relocate(&mut storage)?);
+        let ptr: *mut T = ptr as _;
+        Ok(Self {
+            storage,
+            ptr: NonNull::new(ptr),
+            _owned: PD,
+        })
+    }
+
+    /// Whether the value has been [taken][Self::take_own_ref()] out of `self`.
+    pub
+    fn is_vacant(&self)
+      -> bool
+    {
+        self.ptr.is_none()
+    }
+
+    /// Lends the owned value, _by ownership_, in [`OwnRef`] form, leaving
+    /// `self` [vacant][Self::is_vacant()].
+    ///
+    /// Returns `None` if `self` was already vacant.
+    pub
+    fn take_own_ref(&mut self)
+      -> Option<OwnRef<'_, T>>
+    {
+        let ptr = self.ptr.take()?;
+        let ptr = utils::with_data_ptr(ptr.as_ptr(), self.storage.as_mut_ptr());
+        Some(unsafe {
+            // Safety: not vacant, so `storage` does hold a `T`, which nobody
+            // else will access nor drop now that `self` is vacant.
+            OwnRef::from_raw(ptr as _, [])
+        })
+    }
+
+    fn as_mut_ptr(&mut self)
+      -> *mut T
+    {
+        let ptr = self.ptr.expect("`InlineDyn` to not be vacant");
+        utils::with_data_ptr(ptr.as_ptr(), self.storage.as_mut_ptr())
+    }
+}
+
+impl<T : ?Sized, const N: usize, const ALIGN: usize>
+    Drop
+for
+    InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    fn drop(&mut self)
+    {
+        drop(self.take_own_ref());
+    }
+}
+
+/// Panics if [vacant][InlineDyn::is_vacant()].
+impl<T : ?Sized, const N: usize, const ALIGN: usize>
+    ::core::ops::Deref
+for
+    InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    type Target = T;
+
+    fn deref(&self)
+      -> &T
+    {
+        let ptr = self.ptr.expect("`InlineDyn` to not be vacant");
+        unsafe {
+            // Safety: not vacant, so `storage` does hold a `T`. And it does
+            // so behind an `UnsafeCell`, so that shared access to it still
+            // allows for the `T`'s interior mutability, if any.
+            &*utils::with_data_ptr(ptr.as_ptr(), self.storage.as_ptr())
+        }
+    }
+}
+
+/// Panics if [vacant][InlineDyn::is_vacant()].
+impl<T : ?Sized, const N: usize, const ALIGN: usize>
+    ::core::ops::DerefMut
+for
+    InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    fn deref_mut(&mut self)
+      -> &mut T
+    {
+        unsafe {
+            // Safety: not vacant, so `storage` does hold a `T`.
+            &mut *self.as_mut_ptr()
+        }
+    }
+}
+
+impl<T : ?Sized + ::core::fmt::Debug, const N: usize, const ALIGN: usize>
+    ::core::fmt::Debug
+for
+    InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>)
+      -> ::core::fmt::Result
+    {
+        if self.is_vacant() {
+            f.write_str("<vacant>")
+        } else {
+            (**self).fmt(f)
+        }
+    }
+}
+
+// Same as `Box<T>`.
+unsafe
+impl<T : ?Sized + Send, const N: usize, const ALIGN: usize>
+    Send
+for
+    InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{}
+
+// Same as `Box<T>`.
+unsafe
+impl<T : ?Sized + Sync, const N: usize, const ALIGN: usize>
+    Sync
+for
+    InlineDyn<T, N, ALIGN>
+where
+    Alignment<ALIGN> : ValidAlignment,
+{}
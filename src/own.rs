@@ -3,26 +3,63 @@ use crate::pin::DropFlags;
 
 use super::*;
 use ::core::mem::ManuallyDrop;
+use crate::traits::{DropPolicy, VarianceKind};
 
 mod impls;
 
+mod in_place;
+
+mod split;
+
 /// `&'slot own T`.
+///
+/// ## Stable Rust caveat
+///
+/// For [`own_ref!`] to be usable as `let o = own_ref!(…);`, it has to build
+/// the [`OwnRef`] out of a struct literal, with no function calls involved
+/// (those would stop the lifetime extension of the backing temporary, _c.f._
+/// the "Misusage" section of [`own_ref!`]). On stable Rust, this means the
+/// (`#[doc(hidden)]`) internals of an [`OwnRef`] have to be `pub`, so that
+/// safe code holding an [`OwnRef`] is technically able to overwrite them.
+/// Doing so is unsound, and is to be deemed as off-limits as `unsafe` code
+/// violating its safety contract.
+///
+/// For the same reason, the pointer cannot be a `NonNull` (it would have to
+/// go through `NonNull::new_unchecked()`), so that, on stable Rust,
+/// `Option<OwnRef<'_, T>>` is bigger than `OwnRef<'_, T>`. The other non-null
+/// pointers reachable through `as` casts do not fit either:
+///   - a `&'slot mut T` would make [`OwnRef`] invariant in `T`;
+///   - a `&'slot T` has no write provenance over the value, unless the latter
+///     is wrapped in an `UnsafeCell`, which also makes it invariant in `T`.
+///
+/// With the `nightly` Cargo feature, `super let` lifts that requirement:
+/// these internals are then properly private, and the pointer, non-null.
 // TODO: main crate docs.
 pub
 struct OwnRef<
     'slot,
     T : 'slot + ?Sized,
-    DropFlags : 'static = pin::DropFlags::No,
+    DropFlags : DropPolicy = pin::DropFlags::No,
+    // Not to be specified explicitly: see `_ඞnon_covariant_in_case_of_drop_flags`.
+    Variance : ඞIs<ඞVarianceOf<DropFlags, T>> = ඞVarianceOf<DropFlags, T>,
 > {
     // Since `OwnRef` fields are technically exposed (for the macro to work)
     // we make it "more sound" by requiring an `unsafe`ty token:
     #[doc(hidden)] /** Not part of the public API. */ pub
     _ඞunsafe_to_construct: Unsafe,
     // Alas, this technically isn't 100% sound if we wanted to be pedantic,
-    // since users are technically capable of mutating the following field once
-    // they have their hands on a legitimate instance.
+    // since, on stable Rust, users are technically capable of mutating the
+    // following field once they have their hands on a legitimate instance.
     // Which is why the next field is named `unsafe`, to make it clearer that
-    // mutating it is not safe.
+    // mutating it is not safe. A private-field newtype behind an `unsafe fn`
+    // constructor would seal it, but calling that function from `own_ref!`
+    // would break `let o = own_ref!(…);`, so this is a nightly-only fix
+    // (see the "Stable Rust caveat" docs above). On nightly Rust, the fields
+    // of `Raw` are private, and only constructible through an `unsafe fn`, so
+    // it is properly sealed: the only thing safe code can then do with it is
+    // to `swap()` it with that of another `OwnRef` of the very same type,
+    // which is as harmless as swapping the `OwnRef`s themselves (hence why
+    // the state of the drop policy lives in there as well).
 
     /// Not part of the public API.
     ///
@@ -37,7 +74,6 @@ struct OwnRef<
     //       - or a `* {const,mut} _` (since we can use `as _` casts)
     //       - or a braced struct thereof.
     //   - Able to carry (exclusive) `Write`-access provenance to the `T`.
-    //     (And even beyond `T` in the case of `DropFlags::Yes`.)
     // Among the second point candidates:
     //   - the first point only allows for `&` or `*const`;
     //   - the third point only allows for `&mut` or `*{const,mut}`.
@@ -46,18 +82,20 @@ struct OwnRef<
     // While we'd also love to have this field be:
     //   - non-null
     //   - assumed well-aligned, and unaliased (`&unique T`);
-    // we can't have it all in stable Rust (on nightly Rust we could add the
-    // non-null property through a braced struct newtype around `*const T`).
-    r#unsafe: *const HackMD<PD<&'slot ()>, T>,
+    // we can't have it all in stable Rust.
+    //
+    // On nightly Rust, however, `super let` lets us forgo the lifetime
+    // extension requirements, and thus use a `NonNull` (see `Raw`).
+    r#unsafe: Raw<HackMD<PD<&'slot ()>, T>, <DropFlags as DropPolicy>::State>,
 
     #[doc(hidden)] /** Not part of the public API. */ pub
     _ඞsemantics: PD<OwnRefSemantics<'slot, T>>,
 
-    // Regarding `DropFlags`, we just want an *implicit* `: 'static`.
-    // And now that we are at it, we may as well introduce an implicit
-    // `T : 'slot` as well.
+    // An implicit `T : 'slot`.
+    // (The drop policy, on the other hand, may be short-lived, such as one
+    // returning the value to some borrowed pool.)
     #[doc(hidden)] /** Not part of the public API. */ pub
-    _ඞdrop_flags_marker: PD<fn() -> (&'static DropFlags, &'slot T)>,
+    _ඞdrop_flags_marker: PD<fn() -> &'slot T>,
 
     // A note about covariance: an `&'_ own T`, that is, an `OwnRef<'_, T>`,
     // i.e., an `OwnRef<'_, T, DropFlags::No>`, can, conceptually, be perfectly
@@ -89,10 +127,27 @@ struct OwnRef<
     // parameter (here, `D`), to affect (here, reduce) the variance of another
     // generic parameter (here, `T`). The intuitive `D::Gat<T>` type is
     // currently unconditionally invariant...
+    //
+    // Hence the extra `Variance` parameter: it is the one being used here, and
+    // it is, by default, and by the bound on it, forced to be said
+    // `D::Gat<T>`, _i.e._, `fn(&T)` for `DropFlags::Yes`, and `()` for
+    // `DropFlags::No`. Variance is computed from the fields alone, so this
+    // makes `OwnRef<'_, T>` covariant in `T`, whereas, for
+    // `OwnRef<'_, T, DropFlags::Yes>`, any change of `T` would require a
+    // contravariant change of `Variance`, which the bound rejects.
     #[doc(hidden)] /** Not part of the public API. */ pub
-    _ඞnon_covariant_in_case_of_drop_flags: PD<fn(&T)>,
+    _ඞnon_covariant_in_case_of_drop_flags: PD<Variance>,
 }
 
+/// The `Variance` an <code>[OwnRef]\<\'\_, T, D\></code> is to have.
+#[doc(hidden)] /** Not part of the public API */ pub
+type ඞVarianceOf<D, T> = <<D as DropPolicy>::Variance as VarianceKind>::Of<T>;
+
+/// Type equality, as a trait bound.
+#[doc(hidden)] /** Not part of the public API */ pub
+trait ඞIs<T : ?Sized> {}
+impl<T : ?Sized> ඞIs<T> for T {}
+
 /// What is a `&'slot own T`, after all?
 type OwnRefSemantics<'slot, T> = (
     //  1. it is a `&'slot mut` reference to its backing memory.
@@ -105,39 +160,35 @@ type OwnRefSemantics<'slot, T> = (
     // but we keep it nonetheless for the sake of documentation (to explain why
     // covariance in `T`, much like with `T` or `Box<T>`, is fine).
 );
+// About said `#[may_dangle]` drop impl (à la `Box<T>`): alas, it would not
+// buy us anything. Contrary to a `Box<T>`, an `OwnRef<'slot, T>` does borrow
+// its backing storage, and our drop glue does access it (to drop the `T` in
+// place), so `'slot` itself cannot be `#[may_dangle]` (lest the storage go out
+// of scope before the `OwnRef` be dropped). But then, `T : 'slot` (which any
+// `&'slot mut` borrow of `T`-typed storage requires anyhow), means the
+// lifetimes in `T` have to be live wherever `'slot` is, including when the
+// `OwnRef` is dropped, `#[may_dangle] T` or not.
+// See `fn alas_no_may_dangle()` for a demo.
 
-impl<'slot, T : ?Sized, DropFlags> Drop for OwnRef<'slot, T, DropFlags> {
+impl<'slot, T : ?Sized, DropFlags : DropPolicy, Variance> Drop
+    for OwnRef<'slot, T, DropFlags, Variance>
+where
+    Variance : ඞIs<ඞVarianceOf<DropFlags, T>>,
+{
     fn drop(&mut self)
     {
-        if ::core::mem::needs_drop::<T>() {
-            // Don't forget to clear the drop flag when marked to do so.
-            if PartialEq::eq(
-                &::core::any::TypeId::of::<DropFlags>(),
-                &::core::any::TypeId::of::<pin::DropFlags::Yes>(),
+        unsafe {
+            // Safety: per the whole design of this whole crate:
+            // the pointer is valid, well-aligned, with exclusive write
+            // provenance over `T`, and the `T` itself won't be accessed
+            // as such (_e.g._, won't be dropped) by us after this point.
+            // The state, on the other hand, is not to be accessed again
+            // either, so we can take it (it is `ManuallyDrop`, lest the drop
+            // glue of `Raw` drop it a second time after this).
+            DropFlags::drop_value(
+                ManuallyDrop::take(&mut self.r#unsafe.state),
+                self.r#unsafe.as_ptr() as *mut T,
             )
-            {
-                // Safety: `.unsafe` is a pointer to the `.value`
-                // field of a `ManualOption<T>`, with exclusive write
-                // provenance over it all.
-                let align = ::core::mem::align_of_val::<T>(self);
-                let is_some: *mut bool =
-                    unsafe {
-                        (self.r#unsafe as *mut u8)
-                            .sub(align)
-                    }
-                    .cast()
-                ;
-                unsafe {
-                    *is_some = false;
-                }
-            }
-            unsafe {
-                // Safety: per the whole design of this whole crate:
-                // the pointer is valid, well-aligned, with exclusive write
-                // provenance over `T`, and the `T` itself won't be accessed
-                // as such (_e.g._, won't be dropped) after this point.
-                <*mut T>::drop_in_place(self.r#unsafe as _)
-            }
         }
     }
 }
@@ -157,6 +208,65 @@ impl<'slot, T> OwnRef<'slot, T> {
     }
 }
 
+impl<'slot, T : ?Sized> OwnRef<'slot, T> {
+    /// Moves the owned value to some other backing storage, `dst`.
+    ///
+    /// Whereas [`.deref_move()`][OwnRef::deref_move()] requires `T : Sized`,
+    /// this works with unsized `T`s too, such as `dyn Trait`s or slices, since
+    /// it copies the [`size_of_val()`][::core::mem::size_of_val] bytes of the
+    /// value over, keeping the pointer metadata (vtable, length) intact.
+    ///
+    /// If the value does not fit in `dst`, the original [`OwnRef`] is handed
+    /// back.
+    ///
+    /// ## Example
+    ///
+    /// This is the primitive needed to move an owned trait object from an
+    /// inner scope's slot into an outer scope's slot, before the former ends:
+    ///
+    /// ```rust
+    /// use ::own_ref::{prelude::*, dyn_slot};
+    ///
+    /// let outer = &mut dyn_slot::<32, 8>();
+    /// let f: OwnRef<'_, dyn FnOwn<(), Ret = String>> = {
+    ///     let s = String::from("not copy");
+    ///     let f: OwnRef<'_, dyn FnOwn<(), Ret = String>> = own_ref!(move || s);
+    ///     // `f` cannot escape this scope as is, but:
+    ///     f.relocate(outer).ok().expect("fits")
+    /// };
+    /// assert_eq!(f.call_ownref_0(), "not copy");
+    /// ```
+    pub
+    fn relocate<'new, const SIZE: usize, const ALIGN: usize>(
+        self: OwnRef<'slot, T>,
+        dst: &'new mut DynSlot<SIZE, ALIGN>,
+    ) -> Result<OwnRef<'new, T>, OwnRef<'slot, T>>
+    where
+        crate::traits::Alignment<ALIGN> : crate::traits::ValidAlignment,
+    {
+        let (size, align) = (::core::mem::size_of_val(&*self), ::core::mem::align_of_val(&*self));
+        if DynSlot::<SIZE, ALIGN>::fits_layout(size, align).not() {
+            return Err(self);
+        }
+        let (src, _) = OwnRef::into_raw(self);
+        let dst = utils::with_data_ptr(src, dst.as_mut_ptr());
+        unsafe {
+            // Safety: the layout has been checked against that of `dst`, to
+            // which we have exclusive access for `'new`; and ownership of the
+            // value is transferred since we have disarmed `self`. `dst` does
+            // point to `dst`'s storage with `src`'s metadata, since, on stable
+            // Rust, `with_data_ptr()` asserts that the data pointer is laid
+            // out first within wide pointers.
+            ::core::ptr::copy_nonoverlapping(
+                src.cast::<u8>(),
+                dst.cast::<u8>(),
+                size,
+            );
+            Ok(OwnRef::from_raw(dst, []))
+        }
+    }
+}
+
 /// Main/most useful [`OwnRef`] constructor.
 ///
 /// It works very similarly to [`pin!`], but producing [`OwnRef`]s instead.
@@ -294,22 +404,7 @@ macro_rules! own_ref {( : $T:ty = $value:expr $(,)? ) => ({
         // or the `with()` scoped constructor).
         OwnRef::<'_, $T, $crate::pin::DropFlags::No> {
             _ඞunsafe_to_construct: unsafe { $crate::ඞ::Unsafe::token() },
-            r#unsafe:
-                // main temporary
-                (&mut $crate::ඞ::HackMD::<&(), $T> {
-                    value: $crate::ඞ::MD::new(value),
-                    // extra temporary whose lifetime is not erased.
-                    _temporary: &::core::mem::drop(()),
-                })
-                // `DerefMut` coercion (to yeet the pointer to the extra
-                // temporary into `PhantomData` oblivion (but not its lifetime))
-                as &mut $crate::ඞ::HackMD::<$crate::ඞ::PD<&()>, $T>
-
-                // go through `*mut` to avoid through-`&` provenance loss.
-                // (I'd have loved to use `addr_of_mut!` instead, but it
-                // purposely rejects lifetime extension).
-                as *mut $crate::ඞ::HackMD::<$crate::ඞ::PD<&()>, $T>
-            ,
+            r#unsafe: $crate::ඞown_ref_raw!($T, value),
             _ඞsemantics: $crate::ඞ::PD,
             _ඞdrop_flags_marker: $crate::ඞ::PD,
             _ඞnon_covariant_in_case_of_drop_flags: $crate::ඞ::PD,
@@ -323,6 +418,52 @@ macro_rules! own_ref {( : $T:ty = $value:expr $(,)? ) => ({
     $crate::own_ref! { : _ = $value }
 )}
 
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞown_ref_raw {( $T:ty, $value:expr ) => (
+    $crate::ඞ::Raw {
+        ptr:
+            // main temporary
+            (&mut $crate::ඞ::HackMD::<&(), $T> {
+                value: $crate::ඞ::MD::new($value),
+                // extra temporary whose lifetime is not erased.
+                _temporary: &::core::mem::drop(()),
+            })
+            // `DerefMut` coercion (to yeet the pointer to the extra
+            // temporary into `PhantomData` oblivion (but not its lifetime))
+            as &mut $crate::ඞ::HackMD::<$crate::ඞ::PD<&()>, $T>
+
+            // go through `*mut` to avoid through-`&` provenance loss.
+            // (I'd have loved to use `addr_of_mut!` instead, but it
+            // purposely rejects lifetime extension).
+            as *mut $crate::ඞ::HackMD::<$crate::ඞ::PD<&()>, $T>
+        ,
+        state: $crate::ඞ::MD::new(()),
+    }
+)}
+
+#[cfg(feature = "nightly")]
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+#[allow_internal_unstable(super_let)]
+macro_rules! ඞown_ref_raw {( $T:ty, $value:expr ) => ({
+    // Same as the stable version, but for `super let` extending the
+    // temporaries for us, which frees us to go through an `unsafe fn`.
+    super let mut temporary = $crate::ඞ::HackMD::<&(), $T> {
+        value: $crate::ඞ::MD::new($value),
+        _temporary: &::core::mem::drop(()),
+    };
+    unsafe {
+        // Safety: derived from a reference to a temporary which the
+        // resulting `OwnRef` is lifetime-infected with.
+        $crate::ඞ::Raw::new(
+            &mut temporary as &mut $crate::ඞ::HackMD::<$crate::ඞ::PD<&()>, $T>
+            as *mut $crate::ඞ::HackMD::<$crate::ඞ::PD<&()>, $T>
+            ,
+            (),
+        )
+    }
+})}
+
 impl<'slot, T> OwnRef<'slot, T> {
     /// Low-level [`OwnRef`] construction.
     ///
@@ -395,12 +536,45 @@ impl<'slot, T> OwnRef<'slot, T> {
     }
 }
 
-impl<'slot, T : ?Sized, D> OwnRef<'slot, T, D> {
+impl<'slot, Values : crate::slot::TupleValues> OwnRef<'slot, Values> {
+    /// Same as [`OwnRef::with()`], but for a tuple of `values`, each of them
+    /// ending up in its own [`OwnRef`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ::own_ref::*;
+    ///
+    /// let x = OwnRef::with_all((String::from("…"), 42), |(s, n)| {
+    ///     let _: OwnRef<'_, String> = s;
+    ///     let _: OwnRef<'_, i32> = n;
+    ///     27
+    /// });
+    /// assert_eq!(x, 27);
+    /// ```
+    pub
+    fn with_all<R>(
+        values: Values,
+        scope: impl FnOnce(
+            <Values::Slots as crate::traits::TupleSlots>::OwnRefs<'_>,
+        ) -> R,
+    ) -> R
+    {
+        use crate::traits::TupleSlots;
+        let yield_ = scope;
+        yield_(slots::<Values::Slots>().holding_all(values))
+    }
+}
+
+impl<'slot, T : ?Sized> OwnRef<'slot, T> {
     /// Construct a [`Self`] out of a
     /// <code>&\'slot mut [ManuallyDrop]\<T\></code>.
     ///
     ///   - (Consider the arg pair as acting as one).
     ///
+    /// For <code>[OwnRef]\<\'slot, T, [DropFlags::Yes]\></code>, see
+    /// [`OwnRef::from_raw_flagged()`] instead.
+    ///
     /// # Safety
     ///   0. Casting the ptr to a `&'slot mut ManuallyDrop<T>` must be sound.
     ///
@@ -408,46 +582,150 @@ impl<'slot, T : ?Sized, D> OwnRef<'slot, T, D> {
     ///      _i.e._, since `T` is to be dropped by `Self`, then
     ///      [`ManuallyDrop::take()`] (and/or [`ManuallyDrop::drop()`])
     ///      requirements fully apply.
+    #[inline(always)]
+    pub
+    unsafe
+    fn from_raw(
+        ptr: *mut ManuallyDrop<T>,
+        _you_can_use_this_to_bound_the_lifetime: [&'slot (); 0],
+    ) -> OwnRef<'slot, T>
+    {
+        unsafe {
+            // Safety: delegated to the caller.
+            Self::from_raw_parts(ptr, (), [])
+        }
+    }
+
+    /// Converts the [`OwnRef`] back into its constituent raw pointer,
+    /// disabling the [`Drop`] glue, and whatnot.
     ///
-    ///   2. `D` ought not to be [`pin::DropFlags::Yes`].
+    /// The returned pair is conceptually equivalent to a
+    /// <code>\&\'slot [ManuallyDrop]\<T\></code>, but the usage of a raw
+    /// pointer avoids shrinking provenance of the pointer.
+    #[inline(always)]
+    pub
+    fn into_raw(
+        self: OwnRef<'slot, T>,
+    ) -> (*mut ManuallyDrop<T>, [&'slot (); 0])
+    {
+        (
+            unsafe {
+                // Safety: same layout (pointer to `?Sized`), since the
+                // `DropFlags::No` drop flag is a `()`.
+                ::core::mem::transmute::<
+                    OwnRef<'slot, T>,
+                    *mut ManuallyDrop<T>,
+                >(self)
+            },
+            [],
+        )
+    }
+}
+
+impl<'slot, T : ?Sized> OwnRef<'slot, T, pin::DropFlags::Yes> {
+    /// Construct an <code>[OwnRef]\<\'slot, T, [DropFlags::Yes]\></code>,
+    /// which, when dropped, clears the given `drop_flag` (and drops the `T`).
+    ///
+    /// This is what [`ManualOption`][crate::pin::ManualOption] uses under the
+    /// hood, and what makes it possible for other kinds of backing storage
+    /// (arenas, pools, vectors…) to hand out drop-flagged, and thus, when
+    /// said storage is pinned, [`Pin`]-safe, [`OwnRef`]s.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`OwnRef::from_raw()`], and, moreover:
+    ///
+    ///   - the backing storage must be such that, if `*drop_flag` is still
+    ///     `true` by the time said storage is reclaimed or reüsed (_e.g._,
+    ///     because the [`OwnRef`] got leaked), then the `T` gets dropped in
+    ///     place (by said storage) beforehand.
     ///
-    ///      If it is, then careful with variance! Also, `ptr` must be pointing
-    ///      to the `.value` field of a [`pin::ManualOption`], with exclusive
-    ///      write provenance over the whole `ManualOption`.
+    ///   - for the resulting [`OwnRef`] to be [`Pin::new_unchecked()`]-ed, said
+    ///     storage must, itself, be pinned (or otherwise guaranteed not to be
+    ///     reclaimed without running the aforementioned check).
     ///
-    ///        - (currently that field is not exposed at all publicly since it
-    ///          is a very finicky requirement).
+    ///   - `ptr` and `drop_flag` must be compatible with one another
+    ///     provenance-wise (_e.g._, when they stem from one same raw pointer
+    ///     to the whole backing storage).
+    ///
+    /// [DropFlags::Yes]: crate::pin::DropFlags::Yes
     #[inline(always)]
     pub
     unsafe
-    fn from_raw(
+    fn from_raw_flagged(
         ptr: *mut ManuallyDrop<T>,
+        drop_flag: pin::DropFlag<'slot>,
+    ) -> OwnRef<'slot, T, pin::DropFlags::Yes>
+    {
+        let drop_flag = pin::DropFlag {
+            ptr: drop_flag.ptr,
+            // The lifetime is carried by the `OwnRef` itself.
+            _lifetime: PD,
+        };
+        unsafe {
+            // Safety: delegated to the caller.
+            Self::from_raw_parts(ptr, drop_flag, [])
+        }
+    }
+
+    /// Converts the [`OwnRef`] back into its constituent raw pointer and
+    /// [`DropFlag`][pin::DropFlag], disabling the [`Drop`] glue, and whatnot.
+    ///
+    /// The drop flag is left untouched (_i.e._, still set).
+    #[inline(always)]
+    pub
+    fn into_raw_flagged(
+        self: OwnRef<'slot, T, pin::DropFlags::Yes>,
+    ) -> (*mut ManuallyDrop<T>, pin::DropFlag<'slot>)
+    {
+        let (ptr, drop_flag, []) = OwnRef::into_raw_parts(self);
+        (
+            ptr,
+            pin::DropFlag {
+                ptr: drop_flag.ptr,
+                _lifetime: PD,
+            },
+        )
+    }
+}
+
+impl<'slot, T : ?Sized, D : DropPolicy> OwnRef<'slot, T, D> {
+    /// Construct an <code>[OwnRef]\<\'slot, T, D\></code>, with `D` being
+    /// some custom [`DropPolicy`].
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`OwnRef::from_raw()`], but for the [`Drop`] glue of the
+    /// resulting [`OwnRef`] calling
+    /// <code>D::[drop_value][DropPolicy::drop_value]\(state, ptr)</code>
+    /// instead of dropping the pointee in place: this has to be fine.
+    #[inline(always)]
+    pub
+    unsafe
+    fn from_raw_parts(
+        ptr: *mut ManuallyDrop<T>,
+        state: D::State,
         _you_can_use_this_to_bound_the_lifetime: [&'slot (); 0],
     ) -> OwnRef<'slot, T, D>
     {
-        // check that `D` is one of `No`, `Yes`.
-        {
-            use ::core::any::TypeId;
-            use crate::pin::DropFlags::*;
-            let tid = TypeId::of::<D>();
-            match () {
-                _case if tid == TypeId::of::<Yes>() => {},
-                _case if tid == TypeId::of::<No>() => {},
-                _default => panic!(
-                    "instantiated `OwnRef::<_, D>::from_raw()` with D = {tid:?} not in `DropFlags`",
-                ),
-            }
-        }
         Self {
             _ඞunsafe_to_construct: unsafe {
                 // Safety: delegated to the caller
                 Unsafe::token()
             },
             r#unsafe: unsafe {
-                // Safety: same layout (pointer to `?Sized`).
-                // (this is less error-prone than using casts since it avoids
-                // accidentally affecting provenance)
-                ::core::mem::transmute(ptr)
+                // Safety: delegated to the caller (`ptr` is valid, and thus,
+                // non-null).
+                Raw::new(
+                    // Safety: same layout (pointer to `?Sized`).
+                    // (this is less error-prone than using casts since it
+                    // avoids accidentally affecting provenance)
+                    ::core::mem::transmute::<
+                        *mut ManuallyDrop<T>,
+                        *mut HackMD<PD<&'slot ()>, T>,
+                    >(ptr),
+                    state,
+                )
             },
             _ඞsemantics: <_>::default(),
             _ඞdrop_flags_marker: <_>::default(),
@@ -455,23 +733,26 @@ impl<'slot, T : ?Sized, D> OwnRef<'slot, T, D> {
         }
     }
 
-    /// Converts the [`OwnRef`] back into its constituent raw pointer,
-    /// disabling the [`Drop`] glue, and whatnot.
-    ///
-    /// The returned pair is conceptually equivalent to a
-    /// <code>\&\'slot [ManuallyDrop]\<T\></code>, but the usage of a raw
-    /// pointer avoids shrinking provenance of the pointer, which matters
-    /// when `D` is [`DropFlags::Yes`][crate::pin::DropFlags].
+    /// Converts the [`OwnRef`] back into its constituent raw pointer and
+    /// [`DropPolicy::State`], disabling the [`Drop`] glue, and whatnot.
     #[inline(always)]
     pub
-    fn into_raw(
+    fn into_raw_parts(
         self: OwnRef<'slot, T, D>,
-    ) -> (*mut ManuallyDrop<T>, [&'slot (); 0])
+    ) -> (*mut ManuallyDrop<T>, D::State, [&'slot (); 0])
     {
+        let mut this = ManuallyDrop::new(self);
         (
             unsafe {
-                // Safety: same layout (pointer to `?Sized`)
-                ::core::mem::transmute(self)
+                // Safety: same layout (pointer to `?Sized`).
+                ::core::mem::transmute::<
+                    *mut HackMD<PD<&'slot ()>, T>,
+                    *mut ManuallyDrop<T>,
+                >(this.r#unsafe.as_ptr())
+            },
+            unsafe {
+                // Safety: `this` is never to be used again (nor dropped).
+                ManuallyDrop::take(&mut this.r#unsafe.state)
             },
             [],
         )
@@ -489,6 +770,15 @@ impl<'slot, T : ?Sized, D> OwnRef<'slot, T, D> {
 /// <code>[unsize!]\([own_ref!]\(…\)\)</code> will actually mess up the
 /// temporary lifetime extension shenanigans of [`own_ref!`] ⚠️
 ///
+/// For <code>[Pin]\<[OwnRef]\<\'\_, T, [DropFlags::Yes]\>\></code>s, see
+/// [`pin::unsize!`][crate::pin::unsize!] instead.
+///
+/// For generic `T`s, see [`OwnRef::coerce()`] instead.
+///
+/// On nightly Rust (`nightly` Cargo feature), [`OwnRef`]s (and pinned ones)
+/// are implicitly unsized, much like `Box`es are, making this macro
+/// unnecessary.
+///
 /// ### Example
 ///
 /// ```rust
@@ -523,29 +813,29 @@ macro_rules! unsize {( $e:expr $(,)? ) => (
     }}
 )}
 
-impl<'slot, T : ?Sized, D> ::core::ops::DerefMut for OwnRef<'slot, T, D> {
+// We needn't worry about provenance shrinkage since these are
+// short-lived (`'_`) {nested/re}borrowing operations which only care
+// about accessing the underlying `T`.
+impl<'slot, T : ?Sized, D : DropPolicy> ::core::ops::Deref for OwnRef<'slot, T, D> {
+    type Target = T;
+
+    fn deref(self: &'_ OwnRef<'slot, T, D>)
+      -> &'_ T
+    {
+        &unsafe {
+            // Safety: constructed from a valid reference
+            &*self.r#unsafe.as_ptr()
+        }.value
+    }
+}
+
+impl<'slot, T : ?Sized, D : DropPolicy> ::core::ops::DerefMut for OwnRef<'slot, T, D> {
     fn deref_mut(self: &'_ mut OwnRef<'slot, T, D>)
       -> &'_ mut T
     {
-        // We needn't worry about provenance shrinkage since these are
-        // short-lived (`'_`) {nested/re}borrowing operations which only care
-        // about accessing the underlying `T`.
-        impl<'slot, T : ?Sized, D> ::core::ops::Deref for OwnRef<'slot, T, D> {
-            type Target = T;
-
-            fn deref(self: &'_ OwnRef<'slot, T, D>)
-              -> &'_ T
-            {
-                &unsafe {
-                    // Safety: constructed from a valid reference
-                    &*self.r#unsafe
-                }.value
-            }
-        }
-
         HackMD::unwrap_mut(unsafe {
             // Safety: constructed from a valid reference
-            &mut *self.r#unsafe.cast_mut()
+            &mut *self.r#unsafe.as_ptr()
         })
     }
 }
@@ -554,37 +844,192 @@ mod autotraits {
     use super::*;
 
     unsafe
-    impl<'slot, T : ?Sized, D> Send for OwnRef<'_, T, D>
+    impl<'slot, T : ?Sized, D : DropPolicy, V> Send for OwnRef<'_, T, D, V>
     where
+        V : ඞIs<ඞVarianceOf<D, T>>,
         OwnRefSemantics<'slot, T> : Send,
+        D::State : Send,
     {}
 
     unsafe
-    impl<'slot, T : ?Sized, D> Sync for OwnRef<'_, T, D>
+    impl<'slot, T : ?Sized, D : DropPolicy, V> Sync for OwnRef<'_, T, D, V>
     where
+        V : ඞIs<ඞVarianceOf<D, T>>,
         OwnRefSemantics<'slot, T> : Sync,
     {}
 
-    impl<'slot, T : ?Sized, D> ::core::panic::UnwindSafe for OwnRef<'_, T, D>
+    impl<'slot, T : ?Sized, D : DropPolicy, V> ::core::panic::UnwindSafe for OwnRef<'_, T, D, V>
     where
+        V : ඞIs<ඞVarianceOf<D, T>>,
         OwnRefSemantics<'slot, T> : ::core::panic::UnwindSafe,
     {}
 
-    impl<'slot, T : ?Sized, D> ::core::panic::RefUnwindSafe for OwnRef<'_, T, D>
+    impl<'slot, T : ?Sized, D : DropPolicy, V> ::core::panic::RefUnwindSafe for OwnRef<'_, T, D, V>
     where
+        V : ඞIs<ඞVarianceOf<D, T>>,
         OwnRefSemantics<'slot, T> : ::core::panic::RefUnwindSafe,
     {}
 
     // For this impl, the indirection is important, so we don't use
     // `OwnRefSemantics` (the true semantics are those of a `Box<T>`, but
     // we want to be `no_std`-friendly).
-    impl<'slot, T : ?Sized, D> Unpin for OwnRef<'slot, T, D>
+    impl<'slot, T : ?Sized, D : DropPolicy, V> Unpin for OwnRef<'slot, T, D, V>
     where
+        V : ඞIs<ඞVarianceOf<D, T>>,
         &'slot mut T : Unpin,
     {}
 }
 
 
+/// The type of the `r#unsafe` field of an [`OwnRef`]: its pointer, alongside
+/// the state of its drop policy (which the [`Drop`] glue of the [`OwnRef`]
+/// takes ownership of, hence the `ManuallyDrop`).
+///
+/// On stable Rust, the pointer is a `*const T`, and the fields are `pub`, for
+/// [`own_ref!`] to be able to construct it through lifetime-extension-friendly
+/// `as` casts.
+#[cfg(not(feature = "nightly"))]
+pub
+struct Raw<T : ?Sized, State> {
+    pub ptr: *const T,
+    pub state: ManuallyDrop<State>,
+}
+
+/// The type of the `r#unsafe` field of an [`OwnRef`]: its pointer, alongside
+/// the state of its drop policy (which the [`Drop`] glue of the [`OwnRef`]
+/// takes ownership of, hence the `ManuallyDrop`).
+///
+/// On nightly Rust, the pointer is a `NonNull<T>`, so that
+/// `Option<OwnRef<'_, T>>` be no bigger than `OwnRef<'_, T>`, and the fields
+/// are private, so that safe code cannot tamper with them.
+#[cfg(feature = "nightly")]
+pub
+struct Raw<T : ?Sized, State> {
+    ptr: ::core::ptr::NonNull<T>,
+    pub(in crate) state: ManuallyDrop<State>,
+}
+
+impl<T : ?Sized, State> Raw<T, State> {
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, and the resulting `Raw` must only be used as
+    /// the `r#unsafe` field of an [`OwnRef`] for which `ptr` and `state` are
+    /// valid.
+    #[inline(always)]
+    pub
+    const
+    unsafe
+    fn new(ptr: *mut T, state: State)
+      -> Self
+    {
+        Self {
+            #[cfg(not(feature = "nightly"))]
+            ptr,
+            #[cfg(feature = "nightly")]
+            ptr: unsafe {
+                // Safety: delegated to the caller.
+                ::core::ptr::NonNull::new_unchecked(ptr)
+            },
+            state: ManuallyDrop::new(state),
+        }
+    }
+
+    #[inline(always)]
+    pub(in crate)
+    fn as_ptr(self: &'_ Raw<T, State>)
+      -> *mut T
+    {
+        #[cfg(not(feature = "nightly"))] {
+            self.ptr as *mut T
+        }
+        #[cfg(feature = "nightly")] {
+            self.ptr.as_ptr()
+        }
+    }
+}
+
+/// Implicit unsizing, _e.g._, from <code>[OwnRef]\<\'\_, T\></code> to
+/// <code>[OwnRef]\<\'\_, dyn Trait\></code>, much like with `Box`es (on
+/// nightly Rust, only; otherwise, see [`unsize!`]).
+#[cfg(feature = "nightly")]
+impl<'slot, T, U, D : DropPolicy>
+    ::core::ops::CoerceUnsized<OwnRef<'slot, U, D>>
+for
+    OwnRef<'slot, T, D>
+where
+    T : ?Sized + ::core::marker::Unsize<U>,
+    U : ?Sized,
+{}
+
+// Safety: `Deref{,Mut}` always yield the very same pointee, which moving the
+// `OwnRef` around does not move.
+#[cfg(feature = "nightly")]
+unsafe
+impl<'slot, T : ?Sized, D : DropPolicy>
+    ::core::pin::PinCoerceUnsized
+for
+    OwnRef<'slot, T, D>
+{}
+
+#[cfg(feature = "nightly")]
+impl<T, U, State>
+    ::core::ops::CoerceUnsized<Raw<U, State>>
+for
+    Raw<T, State>
+where
+    T : ?Sized + ::core::marker::Unsize<U>,
+    U : ?Sized,
+{}
+
+/// `self: OwnRef<'_, Self>` receivers on `dyn Trait`s (on nightly Rust, only;
+/// otherwise, see [`FnOwn`][crate::traits::FnOwn] for the `dyn`-dispatchable
+/// `self`-consuming functions use case).
+///
+/// Only drop-flagless [`OwnRef`]s qualify, since the state of other
+/// [`DropPolicy`]s is extra (non-zero-sized) data alongside the pointer.
+///
+/// ```rust
+/// #![feature(arbitrary_self_types)]
+///
+/// use ::own_ref::prelude::*;
+///
+/// trait Consume {
+///     fn consume(self: OwnRef<'_, Self>) -> String;
+/// }
+///
+/// impl Consume for String {
+///     fn consume(self: OwnRef<'_, String>) -> String {
+///         self.deref_move()
+///     }
+/// }
+///
+/// let o: OwnRef<'_, dyn Consume> = own_ref!(String::from("…"));
+/// assert_eq!(o.consume(), "…");
+/// ```
+// (the `Variance` parameters are kept generic, since the dyn-compatibility
+// check does not normalize the `ඞVarianceOf<…>` default of the receiver type.)
+#[cfg(feature = "nightly")]
+impl<'slot, T, U, VT, VU>
+    ::core::ops::DispatchFromDyn<OwnRef<'slot, U, pin::DropFlags::No, VU>>
+for
+    OwnRef<'slot, T, pin::DropFlags::No, VT>
+where
+    T : ?Sized + ::core::marker::Unsize<U>,
+    U : ?Sized,
+    VT : ඞIs<ඞVarianceOf<pin::DropFlags::No, T>>,
+    VU : ඞIs<ඞVarianceOf<pin::DropFlags::No, U>>,
+{}
+
+#[cfg(feature = "nightly")]
+impl<T, U>
+    ::core::ops::DispatchFromDyn<Raw<U, ()>>
+for
+    Raw<T, ()>
+where
+    T : ?Sized + ::core::marker::Unsize<U>,
+    U : ?Sized,
+{}
+
 /// Helper type that allows keeping the type temporary-lifetime-infected,
 /// without encumbering the non-macro case with useless data.
 ///
@@ -604,24 +1049,43 @@ struct HackMD<Lifetime, T : ?Sized> {
 }
 
 impl<__ : ?Sized, T : ?Sized> HackMD<PD<__>, T> {
-    fn wrap_mut<'r>(
-        r: &'r mut MD<T>,
-    ) -> &'r mut HackMD<PD<__>, T>
+    fn wrap_mut(
+        r: &'_ mut MD<T>,
+    ) -> &'_ mut HackMD<PD<__>, T>
     {
         unsafe {
             // Safety: same layout, thanks to `repr(C)`.
-            ::core::mem::transmute(r)
+            ::core::mem::transmute::<
+                &'_ mut MD<T>,
+                &'_ mut HackMD<PD<__>, T>,
+            >(r)
         }
     }
 
-    fn unwrap_mut<'r>(
-        r: &'r mut HackMD<PD<__>, T>,
-    ) -> &'r mut MD<T>
+    fn unwrap_mut(
+        r: &'_ mut HackMD<PD<__>, T>,
+    ) -> &'_ mut MD<T>
     {
         &mut r.value
     }
 }
 
+impl<'temporary, T : ?Sized>
+    ::core::ops::Deref
+for
+    HackMD<&'temporary (), T>
+{
+    type Target = HackMD<PD<&'temporary ()>, T>;
+
+    fn deref(
+        self: &'_ HackMD<&'temporary (), T>,
+    ) -> &'_ HackMD<PD<&'temporary ()>, T>
+    {
+        // Should never need to be called.
+        unimplemented!()
+    }
+}
+
 impl<'temporary, T : ?Sized>
     ::core::ops::DerefMut
 for
@@ -631,22 +1095,6 @@ for
         self: &'_ mut HackMD<&'temporary (), T>,
     ) -> &'_ mut HackMD<PD<&'temporary ()>, T>
     {
-        impl<'temporary, T : ?Sized>
-            ::core::ops::Deref
-        for
-            HackMD<&'temporary (), T>
-        {
-            type Target = HackMD<PD<&'temporary ()>, T>;
-
-            fn deref(
-                self: &'_ HackMD<&'temporary (), T>,
-            ) -> &'_ HackMD<PD<&'temporary ()>, T>
-            {
-                // Should never need to be called.
-                unimplemented!()
-            }
-        }
-
         HackMD::wrap_mut(&mut self.value)
     }
 }
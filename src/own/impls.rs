@@ -3,7 +3,9 @@ use ::core::fmt;
 
 use crate::OwnRef;
 
-impl<'slot, D, T : fmt::Debug> fmt::Debug for OwnRef<'slot, T, D> {
+impl<'slot, D : crate::traits::DropPolicy, T : fmt::Debug> fmt::Debug
+    for OwnRef<'slot, T, D>
+{
     fn fmt(
         self: &'_ OwnRef<'slot, T, D>,
         f: &mut fmt::Formatter<'_>,
@@ -12,3 +14,66 @@ impl<'slot, D, T : fmt::Debug> fmt::Debug for OwnRef<'slot, T, D> {
         (**self).fmt(f)
     }
 }
+
+impl<'slot, T> OwnRef<'slot, Option<T>> {
+    /// Transposes an owned `Option<T>` into an optional owned `T`, _in place_:
+    /// the resulting [`OwnRef`] points to the payload within the original
+    /// backing storage, so no move (nor memcpy) of the `T` is involved.
+    ///
+    /// ```rust
+    /// use ::own_ref::{prelude::*, unsize};
+    ///
+    /// let o: OwnRef<'_, Option<[u8; 4096]>> = own_ref!(Some([42_u8; 4096]));
+    /// let payload: OwnRef<'_, [u8]> = unsize!(o.transpose().unwrap());
+    /// assert_eq!(payload.len(), 4096);
+    /// ```
+    pub
+    fn transpose(
+        self: OwnRef<'slot, Option<T>>,
+    ) -> Option<OwnRef<'slot, T>>
+    {
+        let (ptr, lt) = OwnRef::into_raw(self);
+        match unsafe { &mut *ptr.cast::<Option<T>>() } {
+            // Nothing to drop.
+            | None => None,
+            | Some(value) => Some(unsafe {
+                // Safety: ownership of the `Option` as a whole is transferred
+                // to its (only) payload.
+                OwnRef::from_raw(<*mut T>::cast(value), lt)
+            }),
+        }
+    }
+}
+
+impl<'slot, T, E> OwnRef<'slot, Result<T, E>> {
+    /// Transposes an owned `Result<T, E>` into a `Result` of owned `T` or `E`,
+    /// _in place_: the resulting [`OwnRef`] points to the payload within the
+    /// original backing storage, so no move (nor memcpy) of it is involved.
+    ///
+    /// ```rust
+    /// use ::own_ref::prelude::*;
+    ///
+    /// let r: OwnRef<'_, Result<i32, String>> = own_ref!(Err("…".into()));
+    /// match r.transpose() {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(e) => assert_eq!(e.deref_move(), "…"),
+    /// };
+    /// ```
+    pub
+    fn transpose(
+        self: OwnRef<'slot, Result<T, E>>,
+    ) -> Result<OwnRef<'slot, T>, OwnRef<'slot, E>>
+    {
+        let (ptr, lt) = OwnRef::into_raw(self);
+        match unsafe { &mut *ptr.cast::<Result<T, E>>() } {
+            // Safety (for both): ownership of the `Result` as a whole is
+            // transferred to its (only) active payload.
+            | Ok(value) => Ok(unsafe {
+                OwnRef::from_raw(<*mut T>::cast(value), lt)
+            }),
+            | Err(error) => Err(unsafe {
+                OwnRef::from_raw(<*mut E>::cast(error), lt)
+            }),
+        }
+    }
+}
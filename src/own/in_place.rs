@@ -0,0 +1,184 @@
+//! In-place transformations of the value owned by an [`OwnRef`].
+
+use super::*;
+
+impl<'slot, T> OwnRef<'slot, T> {
+    /// Replaces the owned value with `f(value)`, reüsing the same backing
+    /// storage.
+    ///
+    /// Contrary to the `&mut T` case, where the `take_mut` crate needs to
+    /// `abort()` should `f` panic (since the `&mut T` would otherwise be left
+    /// pointing to a moved-out value), an [`OwnRef`] does not need to care: a
+    /// panic inside `f` simply leaves the slot vacated.
+    ///
+    /// ```rust
+    /// use ::own_ref::prelude::*;
+    ///
+    /// let s: OwnRef<'_, String> = own_ref!(String::from("hello"));
+    /// let s = s.update(|s| s + ", world");
+    /// assert_eq!(*s, "hello, world");
+    /// ```
+    #[inline]
+    pub
+    fn update(
+        self: OwnRef<'slot, T>,
+        f: impl FnOnce(T) -> T,
+    ) -> OwnRef<'slot, T>
+    {
+        self.map_in_place(f)
+    }
+
+    /// Maps the owned value to a `U`, which is written back into the same
+    /// backing storage.
+    ///
+    /// This requires that `U` fit in the memory of `T`; that is, that
+    /// `size_of::<U>() <= size_of::<T>()` and `align_of::<U>() <=
+    /// align_of::<T>()`, which is checked at compile time.
+    ///
+    /// Should `f` panic, the slot is simply left vacated.
+    ///
+    /// ```rust
+    /// use ::own_ref::prelude::*;
+    ///
+    /// let n: OwnRef<'_, u64> = own_ref!(42_u64);
+    /// let bytes: OwnRef<'_, [u8; 8]> = n.map_in_place(u64::to_le_bytes);
+    /// assert_eq!(bytes[0], 42);
+    /// ```
+    ///
+    /// ```rust ,compile_fail
+    /// use ::own_ref::prelude::*;
+    ///
+    /// let n: OwnRef<'_, u32> = own_ref!(42_u32);
+    /// // Error, `u64` does not fit in a `u32`.
+    /// let _: OwnRef<'_, u64> = n.map_in_place(u64::from);
+    /// ```
+    #[inline]
+    pub
+    fn map_in_place<U>(
+        self: OwnRef<'slot, T>,
+        f: impl FnOnce(T) -> U,
+    ) -> OwnRef<'slot, U>
+    {
+        () = Fits::<T, U>::ASSERTION;
+        let (ptr, lt) = OwnRef::into_raw(self);
+        let ptr: *mut T = ptr.cast();
+        unsafe {
+            // Safety: ownership of the `T` is transferred to `f`, and the
+            // (statically checked to be compatible) slot is thus vacated for
+            // us to write the `U` in.
+            let u = f(ptr.read());
+            let ptr: *mut U = ptr.cast();
+            ptr.write(u);
+            OwnRef::from_raw(ptr.cast(), lt)
+        }
+    }
+}
+
+impl<'slot, T> OwnRef<'slot, [T]> {
+    /// Same as [`OwnRef::map_in_place()`], but for mapping each of the elements
+    /// of a slice.
+    ///
+    /// Should `f` panic, the already mapped `U`s, as well as the yet to be
+    /// mapped `T`s, are dropped, and the slot is left vacated.
+    ///
+    /// ```rust
+    /// use ::own_ref::prelude::*;
+    ///
+    /// let names: OwnRef<'_, [String]> = own_ref!([
+    ///     String::from("hello"),
+    ///     String::from("world"),
+    /// ]);
+    /// let lens: OwnRef<'_, [usize]> = names.map_in_place(|s| s.len());
+    /// assert_eq!(*lens, [5, 5]);
+    /// ```
+    pub
+    fn map_in_place<U>(
+        self: OwnRef<'slot, [T]>,
+        mut f: impl FnMut(T) -> U,
+    ) -> OwnRef<'slot, [U]>
+    {
+        () = Fits::<T, U>::ASSERTION;
+        let (ptr, lt) = OwnRef::into_raw(self);
+        let ptr: *mut [T] = ptr as _;
+        let len = unsafe { &*ptr }.len();
+        let mut guard = Progress::<T, U> {
+            ts: ptr.cast(),
+            us: ptr.cast(),
+            len,
+            mapped: 0,
+        };
+        while guard.mapped < len {
+            let i = guard.mapped;
+            unsafe {
+                // Safety: since `U`s are no bigger than `T`s, writing the
+                // `i`-th `U` cannot overwrite the `(i + 1)`-th (and beyond) `T`.
+                let t = guard.ts.add(i).read();
+                // Should `f` panic, `t` has been moved out and is thus not to
+                // be dropped by the guard.
+                guard.mapped += 1;
+                let u = f(t);
+                guard.us.add(i).write(u);
+            }
+        }
+        let us = guard.us;
+        ::core::mem::forget(guard);
+        unsafe {
+            // Safety: all the `len` `U`s have been initialized.
+            OwnRef::from_raw(
+                ::core::ptr::slice_from_raw_parts_mut(us, len) as _,
+                lt,
+            )
+        }
+    }
+}
+
+/// Compile-time check that `U` fits within the memory of a `T`.
+struct Fits<T, U>(PD<(T, U)>);
+
+impl<T, U> Fits<T, U> {
+    const ASSERTION: () = assert!(
+        ::core::mem::size_of::<U>() <= ::core::mem::size_of::<T>()
+        &&
+        ::core::mem::align_of::<U>() <= ::core::mem::align_of::<T>(),
+        "`map_in_place()` requires that `U` fit in the memory of a `T`",
+    );
+}
+
+/// Panic guard of the slice `map_in_place()`.
+struct Progress<T, U> {
+    ts: *mut T,
+    us: *mut U,
+    len: usize,
+    /// Number of `T`s moved out (and thence, modulo an unwinding `f`, of `U`s
+    /// written).
+    mapped: usize,
+}
+
+impl<T, U> Drop for Progress<T, U> {
+    fn drop(&mut self)
+    {
+        // We only get here when unwinding out of `f`: the `mapped`-th `T` has
+        // been consumed, but no `U` has been written in its stead.
+        let written = self.mapped - 1;
+        unsafe {
+            // Safety: these `U`s have been written, and these `T`s, not read.
+            let _drop_remaining_ts_even_if_this_panics = Defer(|| {
+                ::core::ptr::slice_from_raw_parts_mut(
+                    self.ts.add(self.mapped),
+                    self.len - self.mapped,
+                ).drop_in_place();
+            });
+            ::core::ptr::slice_from_raw_parts_mut(self.us, written)
+                .drop_in_place();
+        }
+    }
+}
+
+struct Defer<F : FnMut()>(F);
+
+impl<F : FnMut()> Drop for Defer<F> {
+    fn drop(&mut self)
+    {
+        (self.0)()
+    }
+}
@@ -0,0 +1,172 @@
+//! Splitting an `&own (A, B, …)` into `(&own A, &own B, …)`, and back.
+
+use super::*;
+
+impl<'slot, T> OwnRef<'slot, T> {
+    /// The inverse of `.split()`: (re)joins owned references
+    /// to the elements of a tuple back into an owned reference to the whole
+    /// tuple.
+    ///
+    /// ```rust
+    /// use ::own_ref::prelude::*;
+    ///
+    /// let ab: OwnRef<'_, (String, i32)> = own_ref!((String::from("…"), 42));
+    /// let (a, b) = ab.split();
+    /// let ab = unsafe {
+    ///     // Safety: the parts stem from a `.split()`.
+    ///     OwnRef::join((a, b))
+    /// };
+    /// assert_eq!(ab.deref_move(), (String::from("…"), 42));
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The `parts` must be the elements of one same `(…)` tuple in memory,
+    /// with their pointers having provenance over the whole of it, such as the
+    /// ones yielded by `.split()`.
+    ///
+    /// (That the elements be laid out at the right offsets from one another is
+    /// `debug_assert!`ed, but provenance is not checkable.)
+    #[inline]
+    pub
+    unsafe
+    fn join<Parts>(parts: Parts)
+      -> OwnRef<'slot, T>
+    where
+        Parts : OwnRefParts<'slot, Joined = T>,
+    {
+        unsafe {
+            // Safety: delegated to the caller.
+            parts.join()
+        }
+    }
+}
+
+/// Tuples of [`OwnRef`]s, which may be [`OwnRef::join()`]ed.
+#[doc(hidden)] /** Not part of the public API */ pub
+trait OwnRefParts<'slot> : Sized {
+    type Joined;
+
+    unsafe
+    fn join(self) -> OwnRef<'slot, Self::Joined>;
+}
+
+crate::arities::feed_all!(=> impls!);
+// where
+macro_rules! impls {
+    (
+        /* nothing */
+    ) => (
+        // `()` has no fields to split, and nowhere to join back to.
+    );
+
+    (
+        $($I:ident)+
+    ) => (
+        zip_indices! {
+            [$($I)+]
+            [0 1 2 3 4 5 6 7 8 9 10 11]
+            []
+        }
+    );
+} use impls;
+
+/// Pairs each type parameter with its tuple index, as a literal (for field
+/// access syntax), and then emits the impl.
+macro_rules! zip_indices {
+    (
+        [$I:ident $($Is:ident)*]
+        [$i:tt $($is:tt)*]
+        [$($acc:tt)*]
+    ) => (
+        zip_indices! {
+            [$($Is)*]
+            [$($is)*]
+            [$($acc)* ($I $i)]
+        }
+    );
+
+    (
+        []
+        [$($_:tt)*]
+        [$( ($I:ident $i:tt) )+]
+    ) => (
+        impl<'slot, $($I),+> OwnRef<'slot, ( $($I, )+ )> {
+            /// Splits an owned tuple into owned references to each of its
+            /// elements, _in place_ (that is, without moving any of the
+            /// elements out of the original backing storage).
+            ///
+            /// ```rust
+            /// use ::own_ref::prelude::*;
+            ///
+            /// let pair: OwnRef<'_, (String, Vec<u8>)> = own_ref!((
+            ///     String::from("…"),
+            ///     vec![42_u8; 1024],
+            /// ));
+            /// let (s, v) = pair.split();
+            /// assert_eq!(v.len(), 1024);
+            /// drop(v);
+            /// assert_eq!(s.deref_move(), "…");
+            /// ```
+            pub
+            fn split(
+                self: OwnRef<'slot, ( $($I, )+ )>,
+            ) -> ( $(OwnRef<'slot, $I>, )+ )
+            {
+                let (ptr, lt) = OwnRef::into_raw(self);
+                let ptr: *mut ( $($I, )+ ) = ptr.cast();
+                unsafe {
+                    // Safety: ownership of the whole tuple is transferred,
+                    // disjointly, to each of its fields, with the original
+                    // provenance being kept, so that `join()` may be sound.
+                    ( $(
+                        OwnRef::from_raw(
+                            ::core::ptr::addr_of_mut!((*ptr).$i).cast(),
+                            lt,
+                        ),
+                    )+ )
+                }
+            }
+        }
+
+        impl<'slot, $($I),+> OwnRefParts<'slot> for ( $(OwnRef<'slot, $I>, )+ ) {
+            type Joined = ( $($I, )+ );
+
+            #[inline]
+            unsafe
+            fn join(self)
+              -> OwnRef<'slot, ( $($I, )+ )>
+            {
+                let parts = self;
+                let offsets = {
+                    let tuple = MU::<( $($I, )+ )>::uninit();
+                    let base: *const ( $($I, )+ ) = tuple.as_ptr();
+                    ( $(
+                        unsafe {
+                            // Safety: in-bounds field projection, with no
+                            // reads involved.
+                            ::core::ptr::addr_of!((*base).$i)
+                        } as usize - base as usize,
+                    )+ )
+                };
+                let ptrs = ( $(
+                    OwnRef::into_raw(parts.$i).0.cast::<u8>(),
+                )+ );
+                let base: *mut u8 = ptrs.0.wrapping_sub(offsets.0);
+                $(
+                    debug_assert_eq!(
+                        ptrs.$i,
+                        base.wrapping_add(offsets.$i),
+                        "`OwnRef::join()`: element {} is not laid out where \
+                        it would be within the tuple",
+                        $i,
+                    );
+                )+
+                unsafe {
+                    // Safety: delegated to the caller.
+                    OwnRef::from_raw(base.cast(), [])
+                }
+            }
+        }
+    );
+} use zip_indices;
@@ -2,7 +2,7 @@
 //!
 //! Granted, at first glance, not only is the notion intellectually pleasing,
 //! but it also makes sense to combine these two abstractions, conceptually speaking
-//! (a `pinned_own_ref!(f)` being expected to behave as a more powerful
+//! (a <code>[pinned_own_ref!]\(f\)</code> being expected to behave as a more powerful
 //! <code>[pin!]\(f\)</code>, with some of the ownership semantics of
 //! <code>[Box::pin]\(f)</code> sprinkled on top of it).
 //!
@@ -290,6 +290,10 @@
 
 use super::*;
 use ::core::marker::PhantomPinned;
+use crate::traits::DropPolicy;
+
+pub(in crate)
+mod projection;
 
 /// Even though, structurally, we could have had this impl without writing it
 /// (by virtue of not using [`PhantomPinned`]), I personally find that to be
@@ -337,6 +341,44 @@ impl<T> From<Option<T>> for ManualOption<T> {
     }
 }
 
+impl<T> Default for ManualOption<T> {
+    #[inline]
+    fn default()
+      -> ManualOption<T>
+    {
+        Self::None
+    }
+}
+
+impl<T : Clone> Clone for ManualOption<T> {
+    fn clone(&self)
+      -> ManualOption<T>
+    {
+        self.as_ref().cloned().into()
+    }
+}
+
+impl<T : ::core::fmt::Debug> ::core::fmt::Debug for ManualOption<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>)
+      -> ::core::fmt::Result
+    {
+        match self.as_ref() {
+            Some(value) => f.debug_tuple("Some").field(value).finish(),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl<T : PartialEq> PartialEq for ManualOption<T> {
+    fn eq(&self, other: &ManualOption<T>)
+      -> bool
+    {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T : Eq> Eq for ManualOption<T> {}
+
 impl<T> From<ManualOption<T>> for Option<T> {
     fn from(o: ManualOption<T>)
       -> Option<T>
@@ -390,6 +432,128 @@ impl<T> ManualOption<T> {
         })
     }
 
+    /// Moral equivalent of [`Option::is_some()`].
+    pub
+    const
+    fn is_some(&self)
+      -> bool
+    {
+        self.is_some
+    }
+
+    /// Moral equivalent of [`Option::is_none()`].
+    pub
+    const
+    fn is_none(&self)
+      -> bool
+    {
+        !self.is_some
+    }
+
+    /// Moral equivalent of [`Option::as_pin_ref()`].
+    pub
+    fn as_pin_ref(self: Pin<&Self>)
+      -> Option<Pin<&T>>
+    {
+        Pin::get_ref(self).as_ref().map(|value| unsafe {
+            // Safety: structural pinning.
+            Pin::new_unchecked(value)
+        })
+    }
+
+    /// Moral equivalent of [`Option::as_pin_mut()`].
+    pub
+    fn as_pin_mut(self: Pin<&mut Self>)
+      -> Option<Pin<&mut T>>
+    {
+        unsafe {
+            // Safety: structural pinning.
+            Pin::get_unchecked_mut(self)
+                .as_mut()
+                .map(|value| Pin::new_unchecked(value))
+        }
+    }
+
+    /// Moral equivalent of [`Option::take()`].
+    ///
+    /// Note that a `&mut ManualOption<T>` can only be obtained out of a
+    /// `Pin<&mut ManualOption<T>>` when `T : Unpin`. Otherwise, the only way
+    /// to "take" the value out of a pinned `ManualOption` is to drop it in
+    /// place, by [`.set()`][Pin::set]ting the latter to [`ManualOption::None`].
+    pub
+    fn take(&mut self)
+      -> Option<T>
+    {
+        ::core::mem::replace(self, Self::None).into()
+    }
+
+    /// Moral equivalent of [`Option::replace()`].
+    pub
+    fn replace(&mut self, value: T)
+      -> Option<T>
+    {
+        ::core::mem::replace(self, Self::Some(value)).into()
+    }
+
+    /// Moral equivalent of [`Option::insert()`].
+    ///
+    /// See [`.insert_pinned()`][Self::insert_pinned()] for the pinned variant.
+    pub
+    fn insert(&mut self, value: T)
+      -> &mut T
+    {
+        *self = Self::Some(value);
+        unsafe {
+            // Safety: we have just set it.
+            self.value.assume_init_mut()
+        }
+    }
+
+    /// Moral equivalent of [`Option::get_or_insert_with()`].
+    ///
+    /// See [`.get_or_insert_with_pinned()`][Self::get_or_insert_with_pinned()]
+    /// for the pinned variant.
+    pub
+    fn get_or_insert_with(&mut self, f: impl FnOnce() -> T)
+      -> &mut T
+    {
+        if self.is_none() {
+            *self = Self::Some(f());
+        }
+        unsafe {
+            // Safety: `Some` by now.
+            self.value.assume_init_mut()
+        }
+    }
+
+    /// Same as [`.insert()`][Self::insert()], but for a pinned `self`: the
+    /// previous value, if any, is dropped in place.
+    pub
+    fn insert_pinned(mut self: Pin<&mut Self>, value: T)
+      -> Pin<&mut T>
+    {
+        self.set(Self::Some(value));
+        unsafe {
+            // Safety: structural pinning, and we have just set it.
+            self.map_unchecked_mut(|this| this.value.assume_init_mut())
+        }
+    }
+
+    /// Same as [`.get_or_insert_with()`][Self::get_or_insert_with()], but for
+    /// a pinned `self`.
+    pub
+    fn get_or_insert_with_pinned(mut self: Pin<&mut Self>, f: impl FnOnce() -> T)
+      -> Pin<&mut T>
+    {
+        if self.is_none() {
+            self.set(Self::Some(f()));
+        }
+        unsafe {
+            // Safety: structural pinning, and `Some` by now.
+            self.map_unchecked_mut(|this| this.value.assume_init_mut())
+        }
+    }
+
     /// Same as [`Slot::holding()`], but for it returning a `Pin`ned `value`.
     ///
     /// Uses [runtime drop flags][self] to guard against improper memory leakage,
@@ -419,43 +583,9 @@ impl<T> ManualOption<T> {
         value: T,
     ) -> Pin<OwnRef<'slot, T, DropFlags::Yes>>
     {
+        // Drop any previous value in place, as the `Pin` contract requires.
         self.set(Self::None);
         unsafe {
-            let this = self.get_unchecked_mut();
-            this.value.write(value);
-            this.is_some = true;
-            // We need this cast to a raw pointer because otherwise
-            // `addr_of_mut!` shrinks provenance…
-            // Biggest footgun in Rust, imho.
-            let this: *mut Self = this;
-            // `OwnRef<'_, T, DropFlags::Yes>`' drop glue relies on this.
-            #[cfg(feature = "offset_of")] {
-                impl<T> ManualOption<T> {
-                    const FIELD_OFFSET_ASSERTION: () = assert!(
-                        (
-                            ::core::mem::offset_of!(Self ,value)
-                            -
-                            ::core::mem::align_of::<T>()
-                        ) == (
-                            ::core::mem::offset_of!(Self ,is_some)
-                        )
-                    );
-                }
-                () = ManualOption::<T>::FIELD_OFFSET_ASSERTION;
-            }
-            // Safety:
-            //   - we have just `const`-checked the layout assumption.
-            //   - our raw pointer does indeed behave similarly to a `&mut MD<T>`,
-            //     insofar if the `OwnRef` is indeed dropped, then the `is_some`
-            //     flag is cleared so that our `ManualOption<T>` do nothing,
-            //     thence acting like a `ManuallyDrop<T>`.
-            let own_ref = OwnRef::from_raw(
-                // We have made sure to keep provenance over all of `*self`,
-                // so that the resulting pointer be still allowed to,
-                // eventually, mutate back the `.is_some` field.
-                ::core::ptr::addr_of_mut!((*this).value).cast(),
-                [],
-            );
             // Safety:
             //   - The `Deref{,Mut}` impls are not silly.
             //   - The value is to be dropped before its backing allocation
@@ -466,7 +596,69 @@ impl<T> ManualOption<T> {
             //       - or `is_some` is `true`, and we do drop it.
             //     We know this drop/check will run since we have, our`self`es,
             //     been `Pin`ned, and we're never `Unpin` unless the `value` is.
-            Pin::new_unchecked(own_ref)
+            Pin::new_unchecked(
+                self.get_unchecked_mut().holding_flagged(value)
+            )
+        }
+    }
+
+    /// Same as [`Slot::holding()`], but for the returned [`OwnRef`] using
+    /// [runtime drop flags][self], without any `Pin`ning involved.
+    ///
+    /// This makes for "forget-proof" scope guards: should the [`OwnRef`] be
+    /// leaked (_e.g._, [`mem::forget()`][::core::mem::forget]-ten by some
+    /// callee), the `value` still gets dropped once `self` goes out of scope.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::core::cell::Cell;
+    /// use ::own_ref::{prelude::*, pin::{DropFlags, ManualOption}};
+    ///
+    /// struct Unlock<'r>(&'r Cell<bool>);
+    /// impl Drop for Unlock<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(false);
+    ///     }
+    /// }
+    ///
+    /// fn leaky_callee(guard: OwnRef<'_, Unlock<'_>, DropFlags::Yes>) {
+    ///     ::core::mem::forget(guard);
+    /// }
+    ///
+    /// let locked = Cell::new(true);
+    /// {
+    ///     let mut storage = ManualOption::None;
+    ///     leaky_callee(storage.holding_flagged(Unlock(&locked)));
+    ///     assert!(locked.get());
+    /// } // <- dropped here nonetheless.
+    /// assert!(!locked.get());
+    /// ```
+    pub
+    fn holding_flagged<'slot>(
+        &'slot mut self,
+        value: T,
+    ) -> OwnRef<'slot, T, DropFlags::Yes>
+    {
+        *self = Self::Some(value);
+        // We need this cast to a raw pointer because otherwise
+        // `addr_of_mut!` shrinks provenance…
+        // Biggest footgun in Rust, imho.
+        let this: *mut Self = self;
+        unsafe {
+            // Safety:
+            //   - our raw pointer does indeed behave similarly to a `&mut MD<T>`,
+            //     insofar if the `OwnRef` is indeed dropped, then the `is_some`
+            //     flag is cleared so that our `ManualOption<T>` do nothing,
+            //     thence acting like a `ManuallyDrop<T>`; and if it is leaked
+            //     instead, then `is_some` remains set, and the value gets
+            //     dropped along our `ManualOption<T>` (or leaked with it).
+            //   - both pointers stem from `this`, which has provenance over
+            //     all of `*self`.
+            OwnRef::from_raw_flagged(
+                ::core::ptr::addr_of_mut!((*this).value).cast(),
+                DropFlag::from_raw(::core::ptr::addr_of_mut!((*this).is_some), []),
+            )
         }
     }
 }
@@ -485,6 +677,31 @@ impl<'slot, T> OwnRef<'slot, T, DropFlags::Yes> {
         let yield_ = scope;
         yield_(pin::slot!().holding(value))
     }
+
+    /// Disarms the [`OwnRef`] _and_ clears its drop flag, so that neither it
+    /// nor the backing [`ManualOption`] drop the `T` anymore.
+    ///
+    /// # Safety
+    ///
+    /// The caller is then in charge of dropping the pointee in place (or, at
+    /// least, its structurally pinned parts) before its backing storage is
+    /// invalidated or reüsed, as per the [`Pin`] drop guarantee.
+    #[doc(hidden)] /** Not part of the public API */ pub
+    unsafe
+    fn ඞinto_raw_unflagged(
+        this: Pin<OwnRef<'slot, T, DropFlags::Yes>>,
+    ) -> *mut T
+    {
+        let (ptr, drop_flag) = OwnRef::into_raw_flagged(unsafe {
+            // Safety: the pinning obligations are delegated to the caller.
+            Pin::into_inner_unchecked(this)
+        });
+        unsafe {
+            // Safety: same as in the `Drop` impl of `OwnRef`.
+            drop_flag.ptr.write(false);
+        }
+        ptr.cast()
+    }
 }
 
 #[allow(nonstandard_style)]
@@ -511,8 +728,125 @@ mod DropFlags {
     /// `DropFlags::Yes`, used by the [`pin`][mod@crate::pin]-friendly APIs.
     pub enum Yes {}
 
-    // We don't seal this type-level `enum` for the sake of ergonomics, we'll
-    // just `panic!` if other instantiations are attempted.
+    // Both are `DropPolicy`s, which is what the `D` parameter of an `OwnRef`
+    // is bounded by, so that other instantiations are rejected at compile time
+    // (whereas other policies may be defined through that very trait).
+}
+
+/// Merely drops the value in place.
+impl DropPolicy for DropFlags::No {
+    type State = ();
+    type Variance = crate::traits::Covariant;
+
+    #[inline]
+    unsafe
+    fn drop_value<T : ?Sized>((): (), value: *mut T)
+    {
+        if ::core::mem::needs_drop::<T>() {
+            unsafe {
+                // Safety: delegated to the caller.
+                value.drop_in_place()
+            }
+        }
+    }
+}
+
+/// Clears the drop flag, and then drops the value in place.
+impl DropPolicy for DropFlags::Yes {
+    /// (The actual lifetime is that of the `OwnRef` itself.)
+    type State = DropFlag<'static>;
+    type Variance = crate::traits::Invariant;
+
+    #[inline]
+    unsafe
+    fn drop_value<T : ?Sized>(drop_flag: DropFlag<'static>, value: *mut T)
+    {
+        // Clear the drop flag before dropping the value, lest a panicking
+        // drop glue result in a double drop.
+        unsafe {
+            // Safety: the drop flag is that of the backing storage, to which
+            // we have exclusive access (_c.f._ `OwnRef::from_raw_flagged()`).
+            drop_flag.ptr.write(false);
+            DropFlags::No::drop_value((), value)
+        }
+    }
+}
+
+/// A pointer to the drop flag of some backing storage, to be handed to
+/// [`OwnRef::from_raw_flagged()`].
+///
+/// When the resulting <code>[OwnRef]\<\'slot, T, [DropFlags::Yes]\></code> is
+/// dropped, the flag is cleared (set to `false`), thereby letting the backing
+/// storage know that it is not to drop the value itself.
+pub
+struct DropFlag<'slot> {
+    pub(in crate)
+    ptr: *mut bool,
+
+    pub(in crate)
+    _lifetime: PD<&'slot mut bool>,
+}
+
+/// Same semantics as a `&'slot mut bool`.
+unsafe impl Send for DropFlag<'_> {}
+/// Same semantics as a `&'slot mut bool`.
+unsafe impl Sync for DropFlag<'_> {}
+
+impl<'slot> DropFlag<'slot> {
+    /// Safe constructor, for when the flag is not part of the value's backing
+    /// storage, provenance-wise (_e.g._, a separate allocation).
+    ///
+    /// ```rust
+    /// use ::core::mem::ManuallyDrop;
+    /// use ::own_ref::{prelude::*, pin::{DropFlag, DropFlags}};
+    ///
+    /// let mut value = ManuallyDrop::new(String::from("…"));
+    /// let mut is_some = true;
+    /// let o: OwnRef<'_, String, DropFlags::Yes> = unsafe {
+    ///     OwnRef::from_raw_flagged(&mut value, DropFlag::new(&mut is_some))
+    /// };
+    /// drop(o);
+    /// assert!(!is_some);
+    /// ```
+    #[inline]
+    pub
+    fn new(drop_flag: &'slot mut bool)
+      -> DropFlag<'slot>
+    {
+        Self {
+            ptr: drop_flag,
+            _lifetime: PD,
+        }
+    }
+
+    /// Raw constructor.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes (exclusively so, for the
+    /// `'slot` lifetime).
+    #[inline]
+    pub
+    unsafe
+    fn from_raw(
+        ptr: *mut bool,
+        _you_can_use_this_to_bound_the_lifetime: [&'slot (); 0],
+    ) -> DropFlag<'slot>
+    {
+        Self {
+            ptr,
+            _lifetime: PD,
+        }
+    }
+
+    /// Converts back into the raw pointer.
+    #[inline]
+    pub
+    fn into_raw(self)
+      -> *mut bool
+    {
+        self.ptr
+    }
 }
 
 /// [`pin!`]-friendly version of [`crate::slot()`].
@@ -534,3 +868,379 @@ macro_rules! ඞpinned_slot {() => (
 )}
 #[doc(inline)]
 pub use ඞpinned_slot as slot;
+
+/// [`pin!`]-friendly version of [`crate::slots()`].
+///
+/// Intended to be immediately [`pin!`]ned. Thence the [`slots!`] shorthand.
+pub
+const
+fn slots<Slots>() -> Slots
+where
+    Slots : TupleSlots,
+{
+    Slots::TUPLE_SLOTS
+}
+
+#[doc(hidden)]
+/// Convenience shorthand for <code>[pin!]\([pin::slots()][slots()])</code>.
+///
+/// To be used with [`.holding_all()`][TupleSlots::holding_all].
+///
+/// Since the arity of the tuple cannot always be inferred, it may be
+/// spelled out by listing the (possibly inferred) types of the slots:
+///
+/// ```rust
+/// use ::own_ref::prelude::*;
+///
+/// let storage = pin::slots!(_, _);
+/// let (a, b) = storage.holding_all((async { 42 }, async { 27 }));
+/// let _: Pin<OwnRef<'_, _, pin::DropFlags::Yes>> = a;
+/// let _: Pin<OwnRef<'_, _, pin::DropFlags::Yes>> = b;
+/// ```
+///
+/// An array of slots, each with its own drop flag, can be obtained with the
+/// `pin::slots![T; N]` form:
+///
+/// ```rust
+/// use ::own_ref::prelude::*;
+///
+/// async fn job(n: i32) -> i32 { n }
+///
+/// let storage = pin::slots![_; 2];
+/// let [a, b] = storage.holding_all([job(42), job(27)]);
+/// drop(a);
+/// let _: Pin<OwnRef<'_, _, pin::DropFlags::Yes>> = b;
+/// ```
+#[macro_export]
+macro_rules! ඞpinned_slots {
+    () => (
+        ::core::pin::pin!($crate::pin::slots())
+    );
+    (
+        $T:ty; $N:expr $(,)?
+    ) => (
+        ::core::pin::pin!($crate::pin::slots::<
+            [$crate::pin::ManualOption<$T>; $N]
+        >())
+    );
+    (
+        $($T:ty),+ $(,)?
+    ) => (
+        ::core::pin::pin!($crate::pin::slots::<(
+            $($crate::pin::ManualOption<$T>, )+
+        )>())
+    );
+}
+#[doc(inline)]
+pub use ඞpinned_slots as slots;
+
+#[doc(inline)]
+pub use crate::ඞpin_project as project;
+
+#[doc(hidden)]
+/// [`Pin`]-preserving version of [`unsize!`][crate::unsize!], for
+/// <code>[Pin]\<[OwnRef]\<\'\_, T, [DropFlags::Yes]\>\></code>s.
+///
+/// ```rust
+/// use ::core::future::Future;
+/// use ::own_ref::prelude::*;
+///
+/// let storage = pin::slot!();
+/// let fut = storage.holding(async { 42 });
+/// let fut: Pin<OwnRef<'_, dyn Future<Output = i32>, pin::DropFlags::Yes>> =
+///     pin::unsize!(fut)
+/// ;
+/// ```
+///
+/// The write provenance over the whole backing [`ManualOption`] is preserved,
+/// and so is the [`DropFlag`], which is carried along untouched.
+///
+/// This includes unsizing a pinned, owned array into a pinned, owned slice:
+///
+/// ```rust
+/// use ::own_ref::prelude::*;
+///
+/// async fn job(n: i32) -> i32 { n }
+///
+/// let storage = pin::slot!();
+/// let futs = storage.holding([job(42), job(27)]);
+/// let mut futs: Pin<OwnRef<'_, [_], pin::DropFlags::Yes>> = pin::unsize!(futs);
+/// assert_eq!(futs.as_mut().len(), 2);
+/// ```
+///
+/// On nightly Rust (`nightly` Cargo feature), such pinned [`OwnRef`]s are
+/// implicitly unsized, making this macro unnecessary.
+#[macro_export]
+macro_rules! ඞpin_unsize {( $e:expr $(,)? ) => (
+    match $e { pinned => {
+        let (ptr, drop_flag) = $crate::OwnRef::into_raw_flagged(unsafe {
+            // Safety: re-`Pin`ned right below.
+            $crate::ඞ::Pin::into_inner_unchecked(pinned)
+        });
+        unsafe {
+            // Safety: same as for `unsize!`, with the `ptr` place being where
+            // the unsizing coercion may occur; `Pin`-wise, this is thus just
+            // an unsizing of the `Pin<OwnRef<…>>` we were given.
+            $crate::ඞ::Pin::new_unchecked(
+                $crate::OwnRef::from_raw_flagged(ptr, drop_flag)
+            )
+        }
+    }}
+)}
+#[doc(inline)]
+pub use ඞpin_unsize as unsize;
+
+/// [`Pin`]ned version of [`own_ref!`]: a one-expression constructor of a
+/// <code>[Pin]\<[OwnRef]\<\'\_, T, [DropFlags::Yes]\>\></code>, with built-in
+/// unsizing.
+///
+/// That is, the allocation-free equivalent of
+/// <code>[Box::pin]\(async { … }\) as [Pin]\<[Box]\<dyn [Future]\>\></code>:
+///
+/// ```rust
+/// use ::core::{future::Future, task::{Context, Poll, Waker}};
+/// use ::own_ref::prelude::*;
+///
+/// fn poll_once(
+///     mut fut: Pin<OwnRef<'_, dyn Future<Output = usize> + '_, pin::DropFlags::Yes>>,
+/// ) -> Poll<usize>
+/// {
+/// #   let waker = {
+/// #       use ::core::task::{RawWaker, RawWakerVTable};
+/// #       fn clone(_: *const ()) -> RawWaker { RawWaker::new(::core::ptr::null(), &VTABLE) }
+/// #       const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, drop, drop, drop);
+/// #       unsafe { Waker::from_raw(clone(::core::ptr::null())) }
+/// #   };
+///     fut.as_mut().poll(&mut Context::from_waker(&waker))
+/// }
+///
+/// let captured = String::from("not copy");
+/// let poll = poll_once(pinned_own_ref!(async move { captured.len() }));
+/// assert_eq!(poll, Poll::Ready(8));
+/// ```
+///
+/// ## Syntax
+///
+/// Same as [`own_ref!`]: `pinned_own_ref!(<expr>)`, or
+/// `pinned_own_ref!(: <type> = <expr>)`.
+///
+/// Or, as a statement, `pinned_own_ref!(let <name> = <expr>);`, or
+/// `pinned_own_ref!(let <name>: <type> = <expr>);` (with an optional `mut`
+/// before `<name>`): see below.
+///
+/// ## Limitations
+///
+/// Contrary to [`own_ref!`], the resulting [`Pin`] cannot be _constructed_
+/// in an extending position on stable Rust (since it has no public field), so
+/// the backing [`ManualOption`] is but a temporary which lives until the end of
+/// the enclosing statement. This makes the expression form great for inline
+/// usage, _e.g._, as a function argument, or with `.await`; but for it to be
+/// stored in a `let` binding, you'll have to use the statement form instead,
+/// which declares the backing [`ManualOption`] in a hidden `let` of its own:
+///
+/// ```rust
+/// use ::own_ref::prelude::*;
+///
+/// pinned_own_ref!(let fut: dyn Future<Output = i32> = async { 42 });
+/// drop(fut);
+/// ```
+///
+/// With the `nightly` Cargo feature, on the other hand, `super let` takes care
+/// of it, and `let fut = pinned_own_ref!(async { 42 });` just works.
+#[macro_export]
+macro_rules! pinned_own_ref {
+    (
+        let $name:ident $(: $T:ty)? = $value:expr $(,)?
+    ) => (
+        $crate::ඞpinned_own_ref_let!([] $name [$($T)?] $value)
+    );
+
+    (
+        let mut $name:ident $(: $T:ty)? = $value:expr $(,)?
+    ) => (
+        $crate::ඞpinned_own_ref_let!([mut] $name [$($T)?] $value)
+    );
+
+    (
+        : $T:ty = $value:expr $(,)?
+    ) => (
+        $crate::ඞpinned_own_ref!($T, $value)
+    );
+
+    (
+        $value:expr $(,)?
+    ) => (
+        $crate::pinned_own_ref! { : _ = $value }
+    );
+}
+
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞpinned_own_ref {( $T:ty, $value:expr ) => (
+    // The scrutinee's temporaries, such as the `pin!`ned `ManualOption`,
+    // live until the end of the enclosing statement.
+    match $crate::pin::slot!().holding($value) { pinned => {
+        $crate::ඞpinned_own_ref_unsize!($T, pinned)
+    }}
+)}
+
+#[cfg(feature = "nightly")]
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+#[allow_internal_unstable(super_let)]
+macro_rules! ඞpinned_own_ref {( $T:ty, $value:expr ) => ({
+    // Same as the stable version, but for `super let` extending the
+    // `ManualOption` to the enclosing `let`, if any (much like `pin!` does).
+    super let mut slot = $crate::pin::ManualOption::None;
+    let pinned = unsafe {
+        // Safety: `slot` is not accessible by name outside of this macro, so
+        // it is never to be accessed again, other than through this `Pin`.
+        $crate::ඞ::Pin::new_unchecked(&mut slot)
+    }.holding($value);
+    $crate::ඞpinned_own_ref_unsize!($T, pinned)
+})}
+
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞpinned_own_ref_let {
+    ( [$($mut:tt)?] $name:ident [] $value:expr ) => (
+        $crate::ඞpinned_own_ref_let!([$($mut)?] $name [_] $value)
+    );
+
+    ( [$($mut:tt)?] $name:ident [$T:ty] $value:expr ) => (
+        // Hygiene makes this binding inaccessible to the caller, so that it is
+        // only ever to be accessed through the `Pin` of `$name`.
+        let storage = $crate::pin::slot!();
+        let $($mut)? $name = $crate::ඞpinned_own_ref_unsize!($T, storage.holding($value));
+    );
+}
+
+#[doc(hidden)] /** Not part of the public API */ #[macro_export]
+macro_rules! ඞpinned_own_ref_unsize {( $T:ty, $pinned:expr ) => ({
+    let (ptr, drop_flag) = $crate::OwnRef::into_raw_flagged(unsafe {
+        // Safety: re-`Pin`ned right below.
+        $crate::ඞ::Pin::into_inner_unchecked($pinned)
+    });
+    unsafe {
+        // Safety: same as for `unsize!`, with the `ptr` place being
+        // where the unsizing coercion may occur; `Pin`-wise, this is
+        // thus just an unsizing of the `Pin<OwnRef<…>>` we were given.
+        $crate::ඞ::Pin::<$crate::OwnRef<'_, $T, $crate::pin::DropFlags::Yes>>
+            ::new_unchecked($crate::OwnRef::from_raw_flagged(ptr, drop_flag))
+    }
+})}
+
+/// Tuples (or arrays) of [`ManualOption`]s, such as the ones produced by
+/// [`slots()`].
+///
+/// The [`Pin`]ned counterpart of [`crate::traits::TupleSlots`].
+pub
+trait TupleSlots {
+    /// The tuple of [`ManualOption::None`]s.
+    const TUPLE_SLOTS: Self;
+
+    /// The tuple of values which these slots can be
+    /// [`holding_all()`][Self::holding_all()] of.
+    type Values;
+
+    /// The tuple of `Pin<OwnRef<'slot, _, DropFlags::Yes>>`s yielded by
+    /// [`holding_all()`][Self::holding_all()].
+    type OwnRefs<'slot>
+    where
+        Self : 'slot,
+    ;
+
+    /// Tuple-wise [`ManualOption::holding()`].
+    fn holding_all<'slot>(
+        self: Pin<&'slot mut Self>,
+        values: Self::Values,
+    ) -> Self::OwnRefs<'slot>
+    ;
+}
+
+crate::arities::feed_all!(=> impls!);
+// where
+macro_rules! impls {
+    (
+        $($I:ident)*
+    ) => (
+        impl< $($I),* > TupleSlots for ( $(ManualOption<$I>, )* )
+        {
+            const TUPLE_SLOTS: Self = ( $(ManualOption::<$I>::None, )* );
+
+            type Values = ( $($I, )* );
+
+            type OwnRefs<'slot> = ( $(Pin<OwnRef<'slot, $I, DropFlags::Yes>>, )* )
+            where
+                Self : 'slot,
+            ;
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            fn holding_all<'slot>(
+                self: Pin<&'slot mut Self>,
+                ( $($I, )* ): Self::Values,
+            ) -> Self::OwnRefs<'slot>
+            {
+                ::paste::paste! {
+                    let ( $([<slot $I>], )* ) = unsafe {
+                        // Safety: structural pinning projection, which is fine
+                        // since tuples have no drop glue of their own, and are
+                        // `Unpin` only if all of their fields are.
+                        self.get_unchecked_mut()
+                    };
+                    ( $(
+                        unsafe { Pin::new_unchecked([<slot $I>]) }
+                            .holding($I)
+                    , )* )
+                }
+            }
+        }
+    )
+} use impls;
+
+/// Arrays of [`ManualOption`]s, each with its own drop flag, so that every
+/// <code>[Pin]\<[OwnRef]\<\'slot, T, [DropFlags::Yes]\>\></code> yielded by
+/// [`holding_all()`][TupleSlots::holding_all()] may be dropped independently.
+///
+/// ```rust
+/// use ::core::future::Future;
+/// use ::own_ref::prelude::*;
+///
+/// async fn job(n: i32) -> i32 { n }
+///
+/// let storage = pin::slots![_; 3];
+/// let [a, b, c] = storage.holding_all([job(1), job(2), job(3)]);
+/// drop(b); // dropped in place, on its own.
+/// let _: [Pin<OwnRef<'_, dyn Future<Output = i32>, pin::DropFlags::Yes>>; 2] = [
+///     pin::unsize!(a),
+///     pin::unsize!(c),
+/// ];
+/// ```
+impl<T, const N: usize> TupleSlots for [ManualOption<T>; N] {
+    const TUPLE_SLOTS: Self = [ManualOption::<T>::None; N];
+
+    type Values = [T; N];
+
+    type OwnRefs<'slot> = [Pin<OwnRef<'slot, T, DropFlags::Yes>>; N]
+    where
+        Self : 'slot,
+    ;
+
+    #[inline]
+    fn holding_all<'slot>(
+        self: Pin<&'slot mut Self>,
+        values: [T; N],
+    ) -> [Pin<OwnRef<'slot, T, DropFlags::Yes>>; N]
+    {
+        let mut slots = unsafe {
+            // Safety: structural pinning projection, which is fine since
+            // arrays have no drop glue of their own, and are `Unpin` only if
+            // all of their elements are.
+            self.get_unchecked_mut()
+        }.iter_mut();
+        values.map(|value| {
+            let slot = slots.next().expect("`N` slots for `N` values");
+            unsafe { Pin::new_unchecked(slot) }
+                .holding(value)
+        })
+    }
+}
@@ -0,0 +1,312 @@
+//! [`pin::project!`][crate::pin::project!]: `pin-project`-like projections,
+//! with extra support for [`Pin<OwnRef<'_, T, DropFlags::Yes>>`][OwnRef]s.
+
+#[cfg(doc)]
+use super::*;
+
+/// Drops the pointee in place when dropped itself.
+///
+/// Used to drop the pinned fields of a struct one by one, while retaining the
+/// drop-glue semantics of a struct (_i.e._, the remaining fields are still
+/// dropped should a previous one panic).
+#[doc(hidden)] /** Not part of the public API */ pub
+struct DropInPlace<T>(pub *mut T);
+
+impl<T> Drop for DropInPlace<T> {
+    fn drop(&mut self)
+    {
+        unsafe {
+            // Safety: upheld by the caller which constructed `Self`.
+            self.0.drop_in_place()
+        }
+    }
+}
+
+#[doc(hidden)]
+/// `pin-project`-like projections, with extra support for consuming an owned,
+/// pinned <code>[Pin]\<[OwnRef]\<\'\_, T, [DropFlags::Yes]\>\></code>.
+///
+/// Given a `struct` definition, with some of its fields marked as `#[pin]`,
+/// this emits said `struct` definition, alongside:
+///
+///   - a `fn project(self: Pin<&mut Self>) -> NameProj<'_, …>` method, where
+///     `NameProj` is a `struct` with the same fields, but for them being
+///     `Pin<&mut Field>`s if `#[pin]`-marked, or `&mut Field`s otherwise.
+///
+///     This is thus usable with the <code>.[as_mut][Pin::as_mut]\()</code>
+///     of a <code>[Pin]\<[OwnRef]\<\'\_, Self, [DropFlags::Yes]\>\></code>;
+///
+///   - a `fn project_own(this: Pin<OwnRef<'_, Self, DropFlags::Yes>>) ->
+///     NameProjOwn<…>` associated function, which _consumes_ the pinned,
+///     owned value: the non-`#[pin]` fields are moved out, into the fields of
+///     the returned `NameProjOwn`, whereas the `#[pin]`ned ones are dropped in
+///     place (and are to be found as `PhantomData`s in `NameProjOwn`).
+///
+/// ```rust
+/// use ::core::{future::Future, task::{Context, Poll}};
+/// use ::own_ref::prelude::*;
+///
+/// pin::project! {
+///     struct Timed<F> {
+///         #[pin]
+///         future: F,
+///         polls: usize,
+///     }
+/// }
+///
+/// impl<F : Future> Future for Timed<F> {
+///     type Output = (F::Output, usize);
+///
+///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+///         let this = self.project();
+///         *this.polls += 1;
+///         match this.future.poll(cx) {
+///             Poll::Ready(it) => Poll::Ready((it, *this.polls)),
+///             Poll::Pending => Poll::Pending,
+///         }
+///     }
+/// }
+///
+/// let storage = pin::slot!();
+/// let mut timed = storage.holding(Timed { future: async { 42 }, polls: 0 });
+/// # let waker = {
+/// #     use ::core::task::{RawWaker, RawWakerVTable, Waker};
+/// #     fn clone(_: *const ()) -> RawWaker { RawWaker::new(::core::ptr::null(), &VTABLE) }
+/// #     const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, drop, drop, drop);
+/// #     unsafe { Waker::from_raw(clone(::core::ptr::null())) }
+/// # };
+/// # let cx = &mut Context::from_waker(&waker);
+/// assert_eq!(timed.as_mut().poll(cx), Poll::Ready((42, 1)));
+/// // Consume the whole thing: `future` is dropped in place, `polls` is moved out.
+/// let TimedProjOwn { polls, .. } = Timed::project_own(timed);
+/// assert_eq!(polls, 1);
+/// ```
+///
+/// ## Restrictions
+///
+///   - As with `pin-project`, the `struct` may not have a `Drop` impl, nor a
+///     manual `Unpin` one (one is emitted, only requiring the `#[pin]` fields
+///     to be `Unpin`).
+///
+///   - The `#[pin]` attribute has to come first among the field's attributes.
+///
+///   - Generics bounds are restricted to a single path each (extra bounds may
+///     be expressed in the `where` clause, one path per clause).
+#[macro_export]
+macro_rules! ඞpin_project {(
+    $(#[$attr:meta])*
+    $pub:vis
+    struct $Name:ident
+    $(<
+        $( $lt:lifetime ),* $(,)?
+        $( $T:ident $(: $Bound:path)? ),* $(,)?
+    >)?
+    $(where
+        $( $WcTy:ty : $WcBound:path ),* $(,)?
+    )?
+    {
+        $($fields:tt)*
+    }
+) => (
+    $crate::pin::project! {
+        @fields
+        [$(#[$attr])* $pub $Name]
+        [$( $($lt,)* $($T,)* )?]
+        [$( $($lt,)* $($T $(: $Bound)?,)* )?]
+        [$( $($WcTy : $WcBound,)* )?]
+        [/* struct fields */]
+        [/* projection fields */]
+        [/* projection exprs */]
+        [/* owned projection fields */]
+        [/* pinned field names */]
+        [/* unpinned field names */]
+        $($fields)*
+    }
+);
+
+// `#[pin]` field.
+(
+    @fields $meta:tt $params:tt $generics:tt $wc:tt
+    [$($struct:tt)*]
+    [$($proj:tt)*]
+    [$($exprs:tt)*]
+    [$($own:tt)*]
+    [$($pinned:tt)*]
+    $unpinned:tt
+    #[pin]
+    $(#[$fattr:meta])*
+    $fvis:vis $field:ident : $FTy:ty
+    $(, $($rest:tt)*)?
+) => (
+    $crate::pin::project! {
+        @fields $meta $params $generics $wc
+        [$($struct)* $(#[$fattr])* $fvis $field : $FTy,]
+        [$($proj)* $fvis $field : $crate::ඞ::Pin<&'__pin mut $FTy>,]
+        [$($exprs)* $field : unsafe {
+            // Safety: structural pinning, since `#[pin]`.
+            $crate::ඞ::Pin::new_unchecked($field)
+        },]
+        [$($own)* $fvis $field : $crate::ඞ::PD<$FTy>,]
+        [$($pinned)* $field : $FTy,]
+        $unpinned
+        $($($rest)*)?
+    }
+);
+
+// Unpinned field.
+(
+    @fields $meta:tt $params:tt $generics:tt $wc:tt
+    [$($struct:tt)*]
+    [$($proj:tt)*]
+    [$($exprs:tt)*]
+    [$($own:tt)*]
+    $pinned:tt
+    [$($unpinned:tt)*]
+    $(#[$fattr:meta])*
+    $fvis:vis $field:ident : $FTy:ty
+    $(, $($rest:tt)*)?
+) => (
+    $crate::pin::project! {
+        @fields $meta $params $generics $wc
+        [$($struct)* $(#[$fattr])* $fvis $field : $FTy,]
+        [$($proj)* $fvis $field : &'__pin mut $FTy,]
+        [$($exprs)* $field,]
+        [$($own)* $fvis $field : $FTy,]
+        $pinned
+        [$($unpinned)* $field]
+        $($($rest)*)?
+    }
+);
+
+// Done munching the fields.
+(
+    @fields
+    [$(#[$attr:meta])* $pub:vis $Name:ident]
+    [$($params:tt)*]
+    [$($generics:tt)*]
+    [$($wc:tt)*]
+    [$($struct:tt)*]
+    [$($proj:tt)*]
+    [$($exprs:tt)*]
+    [$($own:tt)*]
+    [$( $pinned:ident : $PTy:ty ,)*]
+    [$($unpinned:ident)*]
+    /* no more fields */
+) => ($crate::ඞ::paste! {
+    $(#[$attr])*
+    $pub
+    struct $Name<$($generics)*>
+    where
+        $($wc)*
+    {
+        $($struct)*
+    }
+
+    #[allow(nonstandard_style, dead_code)]
+    $pub
+    struct [<$Name Proj>]<'__pin, $($generics)*>
+    where
+        $($wc)*
+    {
+        $($proj)*
+    }
+
+    #[allow(nonstandard_style, dead_code)]
+    $pub
+    struct [<$Name ProjOwn>]<$($generics)*>
+    where
+        $($wc)*
+    {
+        $($own)*
+    }
+
+    impl<$($generics)*> $Name<$($params)*>
+    where
+        $($wc)*
+    {
+        #[allow(dead_code)]
+        #[inline]
+        $pub
+        fn project<'__pin>(
+            self: $crate::ඞ::Pin<&'__pin mut Self>,
+        ) -> [<$Name Proj>]<'__pin, $($params)*>
+        {
+            let Self { $($pinned,)* $($unpinned,)* } = unsafe {
+                // Safety: we only hand out `Pin`s to the `#[pin]` fields, and
+                // we make sure `Self` has no `Drop` impl (nor `Unpin` one).
+                $crate::ඞ::Pin::get_unchecked_mut(self)
+            };
+            [<$Name Proj>] {
+                $($exprs)*
+            }
+        }
+
+        #[allow(dead_code)]
+        #[inline]
+        $pub
+        fn project_own(
+            this: $crate::ඞ::Pin<$crate::OwnRef<'_, Self, $crate::pin::DropFlags::Yes>>,
+        ) -> [<$Name ProjOwn>]<$($params)*>
+        {
+            let ptr: *mut Self = unsafe {
+                // Safety: the pinned fields are dropped in place right below.
+                $crate::OwnRef::ඞinto_raw_unflagged(this)
+            };
+            unsafe {
+                // Safety: the unpinned fields are not structurally pinned, so
+                // we can move them out; whereas the pinned ones are dropped in
+                // place, as the `Pin` drop guarantee requires. Since `Self`
+                // has no `Drop` impl, this is equivalent to dropping `*ptr`.
+                let it = [<$Name ProjOwn>] {
+                    $(
+                        $unpinned: ::core::ptr::read(
+                            ::core::ptr::addr_of!((*ptr).$unpinned),
+                        ),
+                    )*
+                    $(
+                        $pinned: $crate::ඞ::PD,
+                    )*
+                };
+                let _drop_in_place = ($(
+                    $crate::ඞ::DropInPlace(
+                        ::core::ptr::addr_of_mut!((*ptr).$pinned),
+                    ),
+                )*);
+                it
+            }
+        }
+    }
+
+    // Emulate a `!Drop` bound on `Self`.
+    const _: () = {
+        #[allow(dead_code)]
+        trait MustNotImplDrop {}
+        #[allow(drop_bounds)]
+        impl<T : ::core::ops::Drop> MustNotImplDrop for T {}
+        impl<$($generics)*> MustNotImplDrop for $Name<$($params)*>
+        where
+            $($wc)*
+        {}
+    };
+
+    // `Self : Unpin` iff all of its `#[pin]` fields are, with the extra
+    // lifetime making the bound non-trivial (and the `fn()`, covering all of
+    // the generic parameters, being `Unpin` no matter what).
+    #[allow(nonstandard_style, dead_code)]
+    struct [<__ $Name Origin>]<'__pin, $($generics)*>
+    where
+        $($wc)*
+    {
+        __lifetime: $crate::ඞ::PD<&'__pin ()>,
+        __generics: $crate::ඞ::PD<fn() -> $Name<$($params)*>>,
+        $(
+            $pinned: $crate::ඞ::PD<$PTy>,
+        )*
+    }
+
+    impl<'__pin, $($generics)*> ::core::marker::Unpin for $Name<$($params)*>
+    where
+        [<__ $Name Origin>]<'__pin, $($params)*> : ::core::marker::Unpin,
+        $($wc)*
+    {}
+})}
@@ -226,9 +226,45 @@ impl<T> MU<T> {
     }
 }
 
+/// Tuples of [`Slot`]s, such as the ones produced by [`slots()`].
 pub
 trait TupleSlots {
+    /// The tuple of [`Slot::VACANT`]s.
     const TUPLE_SLOTS: Self;
+
+    /// The tuple of values which these slots can be
+    /// [`holding_all()`][Self::holding_all()] of.
+    type Values;
+
+    /// The tuple of [`OwnRef`]s yielded by
+    /// [`holding_all()`][Self::holding_all()].
+    type OwnRefs<'slot>
+    where
+        Self : 'slot,
+    ;
+
+    /// Tuple-wise [`Slot::holding()`].
+    ///
+    /// ```rust
+    /// use ::own_ref::{prelude::*, Slot};
+    ///
+    /// let storage = &mut slots::<(Slot<_>, Slot<_>, Slot<_>)>();
+    /// let (a, b, c) = storage.holding_all((42, String::from("…"), ()));
+    /// # let _: (OwnRef<'_, i32>, OwnRef<'_, String>, OwnRef<'_, ()>) = (a, b, c);
+    /// ```
+    ///
+    /// See also [`OwnRef::with_all()`], which infers the slots from the values.
+    fn holding_all<'slot>(
+        &'slot mut self,
+        values: Self::Values,
+    ) -> Self::OwnRefs<'slot>
+    ;
+}
+
+/// Tuples of values, and their matching [`TupleSlots`].
+#[doc(hidden)] /** Not part of the public API */ pub
+trait TupleValues : Sized {
+    type Slots : TupleSlots<Values = Self>;
 }
 
 crate::arities::feed_all!(=> impls!);
@@ -240,6 +276,30 @@ macro_rules! impls {
         impl< $($I),* > TupleSlots for ( $(Slot<$I>, )* )
         {
             const TUPLE_SLOTS: Self = ( $(Slot::<$I>::VACANT, )* );
+
+            type Values = ( $($I, )* );
+
+            type OwnRefs<'slot> = ( $(OwnRef<'slot, $I>, )* )
+            where
+                Self : 'slot,
+            ;
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            fn holding_all<'slot>(
+                &'slot mut self,
+                ( $($I, )* ): Self::Values,
+            ) -> Self::OwnRefs<'slot>
+            {
+                ::paste::paste! {
+                    let ( $([<slot $I>], )* ) = self;
+                    ( $([<slot $I>].holding($I), )* )
+                }
+            }
+        }
+
+        impl< $($I),* > TupleValues for ( $($I, )* ) {
+            type Slots = ( $(Slot<$I>, )* );
         }
     )
 } use impls;
@@ -1,5 +1,36 @@
 use super::*;
 
+/// Hands out guards which count their drops.
+#[derive(Default)]
+struct Drops(::core::cell::Cell<usize>);
+
+impl Drops {
+    /// A guard bumping the drop count (and which is `!Unpin`, so as to be
+    /// usable with the `pin` APIs as well).
+    fn guard(&self)
+      -> ::scopeguard::ScopeGuard<
+            ::core::marker::PhantomPinned,
+            impl '_ + FnOnce(::core::marker::PhantomPinned),
+        >
+    {
+        ::scopeguard::guard(::core::marker::PhantomPinned, |_| {
+            self.0.set(self.0.get() + 1)
+        })
+    }
+
+    fn count(&self)
+      -> usize
+    {
+        self.0.get()
+    }
+
+    #[track_caller]
+    fn assert(&self, count: usize)
+    {
+        assert_eq!(self.count(), count, "unexpected number of drops");
+    }
+}
+
 #[test]
 fn main()
 {
@@ -73,11 +104,8 @@ fn main()
     }
 }
 
-
-/// RIP 😭
-#[cfg(doctest)]
-#[apply(compile_fail!)]
-fn alas_non_covariant()
+#[test]
+fn covariant()
 {
     let local: &str = &String::from("…");
     let a: OwnRef<'_, &'static str> = own_ref!("");
@@ -165,10 +193,9 @@ fn robust_way()
 }
 
 /// For those unconvinced of the need to be non-covariant over `T` in the
-/// `DropFlags` case, replace this with `#[test]`, and the
-/// `_non_covariant_in_case_of_drop_flags` field, with a `PD<fn(&())>` (so that
-/// it becomes covariant again). Then, witness the might of
-/// `cargo +nightly miri test`.
+/// `DropFlags` case, replace this with `#[test]`, and the `Variance` of the
+/// `DropPolicy` impl of `DropFlags::Yes`, with `Covariant`. Then, witness the
+/// might of `cargo +nightly miri test`.
 #[cfg(doctest)]
 #[apply(compile_fail!)]
 fn guard_against_covariance_if_drop_flags() {
@@ -189,3 +216,851 @@ fn guard_against_covariance_if_drop_flags() {
     }
     /* implicit `drop(storage)`, which in turn drops the `PrintOnDrop`. */
 }
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn variance_cannot_be_overridden() {
+    use ::own_ref::pin::DropFlags;
+    // Error, the `Variance` of `DropFlags::Yes` is not `()`.
+    fn covariant(_: OwnRef<'_, &'static str, DropFlags::Yes, ()>) {}
+}
+
+#[test]
+fn dyn_slot_holding() {
+    fn callee<'o>(
+        out: &'o mut DynSlot<32, 8>,
+        s: String,
+    ) -> OwnRef<'o, dyn FnOwn<(), Ret = String>>
+    {
+        unsize!(out.holding(move || s).ok().unwrap())
+    }
+    let storage = &mut dyn_slot();
+    let f = callee(storage, String::from("not copy"));
+    assert_eq!(f.call_ownref_0(), "not copy");
+
+    let too_big = [0_u8; 33];
+    assert_eq!(storage.holding(too_big).unwrap_err(), too_big);
+    let too_aligned = 0_u128;
+    assert_eq!(dyn_slot::<16, 1>().holding(too_aligned).unwrap_err(), 0);
+}
+
+#[test]
+fn inline_dyn() {
+    let drops = &Drops::default();
+    {
+        let not_copy = drops.guard();
+        let f: InlineDyn<dyn FnOwn<(), Ret = ()>, 16> =
+            InlineDyn::from_own_ref(own_ref!(move || drop(not_copy)))
+                .ok()
+                .unwrap()
+        ;
+        let mut f = [f].into_iter().next().unwrap(); // move it around.
+        assert!(f.is_vacant().not());
+        f.take_own_ref().unwrap().call_ownref_0();
+        assert!(f.is_vacant());
+        assert!(f.take_own_ref().is_none());
+    }
+    drops.assert(1);
+    {
+        let not_copy = drops.guard();
+        let f = InlineDyn::<_, 16>::new(move || drop(not_copy)).ok().unwrap();
+        let _moved = Some(f);
+    }
+    drops.assert(2);
+
+    assert_eq!(
+        *InlineDyn::<[u8], 4>::from_own_ref(own_ref!([0_u8; 5])).unwrap_err(),
+        [0; 5],
+    );
+    let mut slice: InlineDyn<[String], 64> =
+        InlineDyn::from_own_ref(own_ref!([String::from("a"), String::from("b")]))
+            .ok()
+            .unwrap()
+    ;
+    slice[1].push('c');
+    assert_eq!(format!("{slice:?}"), r#"["a", "bc"]"#);
+}
+
+#[test]
+fn inline_dyn_interior_mutability() {
+    use ::core::cell::Cell;
+
+    let f: InlineDyn<dyn Fn() -> i32, 16> = {
+        let count = Cell::new(0);
+        InlineDyn::from_own_ref(own_ref!(move || { count.set(count.get() + 1); count.get() }))
+            .ok()
+            .unwrap()
+    };
+    // Through `Deref`, _i.e._, `&InlineDyn`.
+    assert_eq!(f(), 1);
+    assert_eq!(f(), 2);
+    let f = [f].into_iter().next().unwrap(); // move it around.
+    assert_eq!(f(), 3);
+}
+
+#[test]
+fn relocate() {
+    let outer = &mut dyn_slot::<32, 8>();
+    let v: OwnRef<'_, [String]> = {
+        let too_big: OwnRef<'_, dyn ::core::any::Any> =
+            own_ref!([String::from("a"), String::from("b")])
+        ;
+        let Err(too_big) = too_big.relocate(outer) else { unreachable!() };
+        let [a, _b] = too_big.downcast::<[String; 2]>().ok().unwrap().deref_move();
+        let it: OwnRef<'_, [String]> = own_ref!([a]);
+        it.relocate(outer).ok().unwrap()
+    };
+    assert_eq!(*v, ["a"]);
+}
+
+#[test]
+fn holding_all() {
+    let drops = &Drops::default();
+    {
+        let storage = &mut slots::<(Slot<_>, Slot<_>, Slot<_>)>();
+        let (a, b, c) = storage.holding_all((drops.guard(), String::from("…"), drops.guard()));
+        assert_eq!(*b, "…");
+        drop(c);
+        drop(a);
+    }
+    drops.assert(2);
+    let n = OwnRef::with_all((String::from("…"), 42), |(s, n)| {
+        assert_eq!(*s, "…");
+        n.deref_move()
+    });
+    assert_eq!(n, 42);
+    {
+        let storage = pin::slots!(_, _);
+        let (a, b) = storage.holding_all((drops.guard(), drops.guard()));
+        ::core::mem::forget(a);
+        drop(b);
+    }
+    drops.assert(4);
+}
+
+#[test]
+fn split_join() {
+    let drops = &Drops::default();
+    let abc: OwnRef<'_, (_, String, _)> = own_ref!((drops.guard(), String::from("…"), drops.guard()));
+    let (a, b, c) = abc.split();
+    drop(a);
+    drops.assert(1);
+    assert_eq!(*b, "…");
+    let (b, c) = (b.deref_move(), c);
+    assert_eq!(b, "…");
+    drop(c);
+    drops.assert(2);
+    let abc: OwnRef<'_, (u8, u64, u16)> = own_ref!((0_u8, 1_u64, 2_u16));
+    let (a, b, c) = abc.split();
+    let (a, b, c) = unsafe { OwnRef::join((a, b, c)) }.split();
+    let abc = unsafe { OwnRef::join((a, b, c)) };
+    assert_eq!(abc.deref_move(), (0, 1, 2));
+}
+
+#[test]
+fn transpose() {
+    let drops = &Drops::default();
+    let o: OwnRef<'_, Option<_>> = own_ref!(Some(drops.guard()));
+    let o = o.transpose().unwrap();
+    drops.assert(0);
+    drop(o);
+    drops.assert(1);
+    let o: OwnRef<'_, Option<String>> = own_ref!(None);
+    assert!(o.transpose().is_none());
+    let r: OwnRef<'_, Result<_, String>> = own_ref!(Ok(drops.guard()));
+    drop(r.transpose().ok().unwrap());
+    drops.assert(2);
+    let r: OwnRef<'_, Result<String, _>> = own_ref!(Err(drops.guard()));
+    drop(r.transpose().err().unwrap());
+    drops.assert(3);
+}
+
+#[test]
+fn own_project() {
+    #[::macro_rules_attribute::derive(crate::OwnProject!)]
+    enum Generic<'a, T : Sized, U>
+    where
+        U : ::core::fmt::Debug,
+    {
+        Borrowed(&'a str, T),
+        Owned { t: T, u: U },
+        #[allow(dead_code)]
+        Nothing,
+    }
+
+    let drops = &Drops::default();
+    let e: OwnRef<'_, Generic<'_, _, i32>> = own_ref!(Generic::Borrowed("…", drops.guard()));
+    match e.project() {
+        GenericOwn::Borrowed(s, g) => {
+            assert_eq!(*s, "…");
+            drops.assert(0);
+            drop(g);
+            drops.assert(1);
+        },
+        _ => unreachable!(),
+    }
+    let e: OwnRef<'_, Generic<'_, _, String>> = own_ref!(Generic::Owned {
+        t: drops.guard(),
+        u: String::from("…"),
+    });
+    match e.project() {
+        GenericOwn::Owned { t, u } => {
+            drop(t);
+            assert_eq!(u.deref_move(), "…");
+        },
+        _ => unreachable!(),
+    }
+    drops.assert(2);
+}
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn own_project_no_drop_impl()
+{
+    #[::macro_rules_attribute::derive(::own_ref::OwnProject!)]
+    enum Guarded {
+        A(String),
+    }
+
+    // Error: conflicting implementations of trait `MustNotImplDrop`.
+    impl Drop for Guarded {
+        fn drop(&mut self) {}
+    }
+}
+
+#[test]
+fn own_project_unit_only() {
+    #[::macro_rules_attribute::derive(crate::OwnProject!)]
+    enum Direction {
+        Left,
+        Right,
+    }
+
+    fn is_left(d: OwnRef<'_, Direction>) -> bool {
+        match d.project() {
+            DirectionOwn::Left => true,
+            DirectionOwn::Right => false,
+        }
+    }
+
+    assert!(is_left(own_ref!(Direction::Left)));
+    assert!(is_left(own_ref!(Direction::Right)).not());
+}
+
+#[test]
+fn pin_project() {
+    pin::project! {
+        struct Pair<'a, T> {
+            #[pin]
+            pinned: T,
+            name: &'a str,
+            unpinned: String,
+        }
+    }
+
+    let drops = &Drops::default();
+    {
+        let storage = pin::slot!();
+        let mut pair = storage.holding(Pair {
+            pinned: drops.guard(),
+            name: "…",
+            unpinned: String::from("unpinned"),
+        });
+        {
+            let this = pair.as_mut().project();
+            let _: Pin<&mut _> = this.pinned;
+            this.unpinned.push('!');
+            assert_eq!(*this.name, "…");
+        }
+        let PairProjOwn { unpinned, .. } = Pair::project_own(pair);
+        drops.assert(1);
+        assert_eq!(unpinned, "unpinned!");
+    } // <- `storage` is not to drop the `pinned` field again.
+    drops.assert(1);
+}
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn pin_project_no_drop_impl()
+{
+    pin::project! {
+        struct Foo<T> {
+            #[pin]
+            pinned: T,
+        }
+    }
+
+    // Error: conflicting implementations of trait `MustNotImplDrop`.
+    impl<T> Drop for Foo<T> {
+        fn drop(&mut self) {}
+    }
+}
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn pin_project_not_unpin()
+{
+    pin::project! {
+        struct Foo<T> {
+            #[pin]
+            pinned: T,
+            unpinned: (),
+        }
+    }
+
+    fn is_unpin<T : Unpin>() {}
+    // Error: `PhantomPinned` cannot be unpinned.
+    is_unpin::<Foo<::core::marker::PhantomPinned>>();
+}
+
+#[test]
+fn update_and_map_in_place() {
+    let s: OwnRef<'_, String> = own_ref!(String::from("…"));
+    let s = s.update(|s| s + "!");
+    let len = s.map_in_place(|s| s.len());
+    assert_eq!(*len, "…!".len());
+
+    let drops = &Drops::default();
+    let guards: OwnRef<'_, [_]> = own_ref!([0_u64, 1, 2, 3].map(|i| (drops.guard(), i)));
+    let panicked = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+        let mut i = 0;
+        guards.map_in_place(|(g, _)| {
+            i += 1;
+            if i == 3 {
+                panic!();
+            }
+            g
+        })
+    }));
+    assert!(panicked.is_err());
+    // Mapped, unmapped, and in-flight elements alike: each dropped once.
+    drops.assert(4);
+
+    let storage = &mut slot();
+    let o = storage.holding(drops.guard());
+    let panicked = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+        o.update(|_| panic!())
+    }));
+    assert!(panicked.is_err());
+    drops.assert(5);
+}
+
+#[test]
+fn pinned_own_ref() {
+    let drops = &Drops::default();
+    fn forget(p: Pin<OwnRef<'_, dyn ::core::fmt::Debug + '_, pin::DropFlags::Yes>>) {
+        ::core::mem::forget(p);
+    }
+    forget(pinned_own_ref!(drops.guard()));
+    // `ManualOption`'s drop glue caught the leak.
+    drops.assert(1);
+    let n = pinned_own_ref!(: [_] = [drops.guard(), drops.guard()]).len();
+    assert_eq!((n, drops.count()), (2, 3));
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn pinned_own_ref_let() {
+    let drops = &Drops::default();
+    {
+        let p: Pin<OwnRef<'_, dyn ::core::fmt::Debug + '_, pin::DropFlags::Yes>> =
+            pinned_own_ref!(drops.guard())
+        ;
+        drops.assert(0);
+        ::core::mem::forget(p);
+        drops.assert(0);
+    } // `ManualOption`'s drop glue caught the leak, at the end of the scope.
+    drops.assert(1);
+}
+
+#[test]
+fn pinned_own_ref_let_statement() {
+    let drops = &Drops::default();
+    {
+        pinned_own_ref!(let p: dyn ::core::fmt::Debug + '_ = drops.guard());
+        drops.assert(0);
+        ::core::mem::forget(p);
+        drops.assert(0);
+        pinned_own_ref!(let mut q = drops.guard());
+        q.set(drops.guard());
+        drops.assert(1);
+    } // `ManualOption`'s drop glue caught the leak, at the end of the scope.
+    drops.assert(3);
+}
+
+#[cfg(all(doctest, not(feature = "nightly")))]
+#[apply(compile_fail!)]
+fn pinned_own_ref_let_needs_nightly()
+{
+    let fut = pinned_own_ref!(async { 42 });
+    // Error, temporary value dropped while borrowed.
+    drop(fut);
+}
+
+#[test]
+fn pin_unsize() {
+    let drops = &Drops::default();
+    {
+        let storage = pin::slot!();
+        let p = storage.holding([drops.guard(), drops.guard()]);
+        let p: Pin<OwnRef<'_, [_], pin::DropFlags::Yes>> = pin::unsize!(p);
+        assert_eq!(p.len(), 2);
+        ::core::mem::forget(p);
+    } // <- drop flags still caught the leak.
+    drops.assert(2);
+    {
+        let storage = pin::slot!();
+        let p = storage.holding((0_u8, drops.guard(), 0_u64));
+        let p: Pin<OwnRef<'_, dyn ::core::fmt::Debug + '_, pin::DropFlags::Yes>> = pin::unsize!(p);
+        drop(p);
+        drops.assert(3);
+    }
+    drops.assert(3);
+}
+
+#[test]
+fn manual_option() {
+    use pin::ManualOption;
+
+    let mut o = ManualOption::<String>::default();
+    assert!(o.is_none());
+    assert_eq!(format!("{o:?}"), "None");
+    assert_eq!(o.get_or_insert_with(|| "a".into()), "a");
+    assert_eq!(o.replace("b".into()).as_deref(), Some("a"));
+    assert_eq!(o.clone(), ManualOption::Some("b".into()));
+    assert_eq!(format!("{o:?}"), r#"Some("b")"#);
+    assert_eq!(o.insert("c".into()), "c");
+    assert_eq!(o.take().as_deref(), Some("c"));
+    assert!(o.is_none());
+
+    let drops = &Drops::default();
+    let mut o = pin!(ManualOption::None);
+    assert!(o.as_mut().as_pin_mut().is_none());
+    o.as_mut().get_or_insert_with_pinned(|| drops.guard());
+    o.as_mut().get_or_insert_with_pinned(|| unreachable!());
+    assert!(o.as_ref().as_pin_ref().is_some());
+    o.as_mut().insert_pinned(drops.guard());
+    drops.assert(1);
+    o.set(ManualOption::None);
+    drops.assert(2);
+}
+
+#[test]
+fn holding_flagged() {
+    use pin::ManualOption;
+
+    let drops = &Drops::default();
+
+    let mut storage = ManualOption::None;
+    drop(storage.holding_flagged(drops.guard()));
+    drops.assert(1);
+    assert!(storage.is_none());
+
+    ::core::mem::forget(storage.holding_flagged(drops.guard()));
+    drops.assert(1);
+    assert!(storage.is_some());
+    // Overwriting the storage drops the leaked value.
+    ::core::mem::forget(storage.holding_flagged(drops.guard()));
+    drops.assert(2);
+    drop(storage);
+    drops.assert(3);
+}
+
+#[test]
+fn pinned_slices() {
+    let drops = &Drops::default();
+    {
+        let storage = pin::slots![_; 3];
+        let [a, b, c] = storage.holding_all([drops.guard(), drops.guard(), drops.guard()]);
+        ::core::mem::forget(a);
+        drop((b, c));
+        drops.assert(2);
+    }
+    // The leaked one is dropped along its slot.
+    drops.assert(3);
+
+    let mut storage = pin::slot!();
+    let array = storage.as_mut().holding([drops.guard(), drops.guard()]);
+    let slice: Pin<OwnRef<'_, [_], pin::DropFlags::Yes>> = pin::unsize!(array);
+    assert_eq!(slice.len(), 2);
+    drop(slice);
+    drops.assert(5);
+    assert!(storage.is_none());
+}
+
+#[test]
+fn out_of_line_drop_flags() {
+    use ::core::mem::MaybeUninit;
+    use pin::{DropFlag, DropFlags};
+
+    /// Some storage with its drop flags kept apart from its values.
+    struct Pool<T> {
+        values: [MaybeUninit<T>; 2],
+        flags: [bool; 2],
+    }
+
+    impl<T> Pool<T> {
+        fn holding(&mut self, i: usize, value: T) -> OwnRef<'_, T, DropFlags::Yes> {
+            assert!(!self.flags[i]);
+            self.values[i].write(value);
+            self.flags[i] = true;
+            let this: *mut Self = self;
+            unsafe {
+                OwnRef::from_raw_flagged(
+                    ::core::ptr::addr_of_mut!((*this).values[i]).cast(),
+                    DropFlag::from_raw(::core::ptr::addr_of_mut!((*this).flags[i]), []),
+                )
+            }
+        }
+    }
+
+    impl<T> Drop for Pool<T> {
+        fn drop(&mut self) {
+            for (value, &is_some) in self.values.iter_mut().zip(&self.flags) {
+                if is_some {
+                    unsafe { value.assume_init_drop() }
+                }
+            }
+        }
+    }
+
+    let drops = &Drops::default();
+    let mut pool = Pool { values: [MaybeUninit::uninit(), MaybeUninit::uninit()], flags: [false; 2] };
+    drop(pool.holding(0, drops.guard()));
+    drops.assert(1);
+    assert_eq!(pool.flags, [false, false]);
+    let leaked: OwnRef<'_, dyn ::core::fmt::Debug + '_, DropFlags::Yes> = {
+        let (ptr, drop_flag) = OwnRef::into_raw_flagged(pool.holding(1, drops.guard()));
+        unsafe { OwnRef::from_raw_flagged(ptr, drop_flag) }
+    };
+    ::core::mem::forget(leaked);
+    assert_eq!(pool.flags, [false, true]);
+    drop(pool);
+    drops.assert(2);
+}
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn drop_policy_is_required() {
+    // Error, `()` is not a `DropPolicy`.
+    let _: Option<OwnRef<'static, i32, ()>> = None;
+}
+
+#[test]
+fn drop_policy() {
+    use ::core::{cell::Cell, marker::PhantomData, mem::ManuallyDrop};
+    use traits::DropPolicy;
+
+    /// Counts the drops, and defers them to the end of the scope.
+    struct Deferred<'scope>(PhantomData<&'scope ()>);
+
+    impl<'scope> DropPolicy for Deferred<'scope> {
+        type State = &'scope Cell<usize>;
+        type Variance = traits::Covariant;
+
+        unsafe fn drop_value<T : ?Sized>(deferred: &'scope Cell<usize>, _: *mut T) {
+            deferred.set(deferred.get() + 1);
+        }
+    }
+
+    let deferred = &Cell::new(0);
+    let mut values = [ManuallyDrop::new(String::from("a")), ManuallyDrop::new(String::from("b"))];
+    let [a, b] = &mut values;
+    let a = unsafe { OwnRef::<_, Deferred<'_>>::from_raw_parts(a, deferred, []) };
+    let b = unsafe { OwnRef::<_, Deferred<'_>>::from_raw_parts(b, deferred, []) };
+    assert_eq!(*a, "a");
+    drop(a);
+    assert_eq!(deferred.get(), 1);
+    let (ptr, state, []) = OwnRef::into_raw_parts(b);
+    let b = unsafe { OwnRef::<String, Deferred<'_>>::from_raw_parts(ptr, state, []) };
+    drop(b);
+    assert_eq!(deferred.get(), 2);
+    for value in &mut values {
+        unsafe { ManuallyDrop::drop(value) }
+    }
+}
+
+#[test]
+fn drop_policy_state_is_dropped_once() {
+    use ::core::{marker::PhantomData, mem::ManuallyDrop};
+    use traits::DropPolicy;
+
+    trait Erased {}
+    impl<T> Erased for T {}
+
+    /// Leaks the values, but drops its (non-trivial) state.
+    struct Leak<'scope>(PhantomData<&'scope ()>);
+
+    impl<'scope> DropPolicy for Leak<'scope> {
+        type State = Box<dyn Erased + 'scope>;
+        type Variance = traits::Covariant;
+
+        unsafe fn drop_value<T : ?Sized>(_: Self::State, _: *mut T) {}
+    }
+
+    let drops = &Drops::default();
+    let mut values = [ManuallyDrop::new(0), ManuallyDrop::new(1)];
+    let [a, b] = &mut values;
+    let a = unsafe { OwnRef::<_, Leak<'_>>::from_raw_parts(a, Box::new(drops.guard()), []) };
+    drop(a);
+    drops.assert(1);
+    let b = unsafe { OwnRef::<_, Leak<'_>>::from_raw_parts(b, Box::new(drops.guard()), []) };
+    let (_, state, []) = OwnRef::into_raw_parts(b);
+    drops.assert(1);
+    drop(state);
+    drops.assert(2);
+}
+
+#[test]
+fn null_pointer_niche() {
+    use ::core::mem::size_of;
+
+    assert_eq!(size_of::<OwnRef<'_, u8>>(), size_of::<*mut u8>());
+    assert_eq!(size_of::<OwnRef<'_, [u8]>>(), size_of::<*mut [u8]>());
+    // Only with `nightly`: see the "Stable Rust caveat" of `OwnRef`.
+    #[cfg(feature = "nightly")] {
+        assert_eq!(size_of::<Option<OwnRef<'_, u8>>>(), size_of::<OwnRef<'_, u8>>());
+        assert_eq!(size_of::<Option<OwnRef<'_, [u8]>>>(), size_of::<OwnRef<'_, [u8]>>());
+        assert_eq!(
+            size_of::<Option<OwnRef<'_, str, pin::DropFlags::Yes>>>(),
+            size_of::<OwnRef<'_, str, pin::DropFlags::Yes>>(),
+        );
+    }
+    let o: Option<OwnRef<'_, i32>> = Some(own_ref!(42));
+    assert_eq!(o.as_deref(), Some(&42));
+}
+
+#[cfg(all(doctest, feature = "nightly"))]
+#[apply(compile_fail!)]
+fn own_ref_ptr_cannot_be_mutated() {
+    let mut o = own_ref!(42);
+    let mut other = ::core::mem::ManuallyDrop::new(27);
+    // Error, field `ptr` is private.
+    o.r#unsafe.ptr = ::core::ptr::NonNull::from(&mut other).cast();
+}
+
+#[cfg(all(doctest, feature = "nightly"))]
+#[apply(compile_fail!)]
+fn own_ref_raw_cannot_be_safely_constructed() {
+    let mut o = own_ref!(42);
+    let mut other = ::core::mem::ManuallyDrop::new(27);
+    // Error, fields `ptr` and `state` are private.
+    o.r#unsafe = ඞ::Raw { ptr: ::core::ptr::NonNull::from(&mut other).cast(), state: ::core::mem::ManuallyDrop::new(()) };
+}
+
+// On stable Rust, the fields of `Raw` are `pub`, so it is not sealed: see the
+// "Stable Rust caveat" of `OwnRef`.
+#[cfg(all(doctest, feature = "nightly"))]
+#[apply(compile_fail!)]
+fn own_ref_raw_cannot_be_safely_overwritten() {
+    let mut o = own_ref!(42);
+    let mut other = ::core::mem::ManuallyDrop::new(27);
+    // Error, `Raw::new()` is `unsafe`.
+    o.r#unsafe = ඞ::Raw::new(&mut *other as *mut i32 as *mut _, ());
+}
+
+#[cfg(all(doctest, feature = "nightly"))]
+#[apply(compile_fail!)]
+fn own_ref_drop_state_cannot_be_mutated() {
+    let mut storage = pin::ManualOption::None;
+    let mut o = storage.holding_flagged(42);
+    // Error, field `state` is private.
+    o.r#unsafe.state = ::core::mem::ManuallyDrop::new(pin::DropFlag::new(Box::leak(Box::new(true))));
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn coerce_unsized() {
+    let drops = &Drops::default();
+    {
+        let mut slot = slot();
+        let guard = drops.guard();
+        let f: OwnRef<'_, dyn FnOwn<(), Ret = i32> + '_> = slot.holding(move || {
+            drop(guard);
+            42
+        });
+        assert_eq!(f.call_ownref_0(), 42);
+        drops.assert(1);
+    }
+    {
+        let s: OwnRef<'_, [_]> = own_ref!([drops.guard(), drops.guard()]);
+        assert_eq!(s.len(), 2);
+    }
+    drops.assert(3);
+    {
+        let storage = pin::slot!();
+        let p: Pin<OwnRef<'_, [_], pin::DropFlags::Yes>> = storage.holding([drops.guard()]);
+        ::core::mem::forget(p);
+    } // <- drop flags still caught the leak.
+    drops.assert(4);
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn dispatch_from_dyn() {
+    trait Consume {
+        fn consume(self: OwnRef<'_, Self>) -> usize;
+    }
+
+    impl Consume for String {
+        fn consume(self: OwnRef<'_, Self>) -> usize {
+            self.deref_move().len()
+        }
+    }
+
+    impl<T> Consume for [T; 2] {
+        fn consume(self: OwnRef<'_, Self>) -> usize {
+            let [a, b] = self.deref_move();
+            drop((a, b));
+            2
+        }
+    }
+
+    let drops = &Drops::default();
+    let os: [OwnRef<'_, dyn Consume + '_>; 2] = [
+        own_ref!(String::from("hello")),
+        own_ref!([drops.guard(), drops.guard()]),
+    ];
+    let [a, b] = os;
+    assert_eq!(a.consume(), 5);
+    assert_eq!(b.consume(), 2);
+    drops.assert(2);
+}
+
+/// Thanks to `#[may_dangle]`, a `Box` may outlive the borrows in its pointee,
+/// provided it does not use them when dropped (_c.f._ `alas_no_may_dangle`).
+#[test]
+#[allow(clippy::needless_late_init)] // declared before `s`, on purpose.
+fn box_may_dangle()
+{
+    let b: Box<Vec<&str>>;
+    let s = String::from("…");
+    b = Box::new(vec![&s[..]]);
+    assert_eq!(b.len(), 1);
+}
+
+/// RIP 😭 (see the comment after `OwnRefSemantics`): the very same as
+/// `box_may_dangle`, but with an `OwnRef`, fails.
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn alas_no_may_dangle()
+{
+    let mut storage = slot();
+    let o: OwnRef<'_, Vec<&str>>;
+    let s = String::from("…");
+    // Error, `s` does not live long enough (`T : 'slot`).
+    o = storage.holding(vec![&s[..]]);
+}
+
+#[test]
+fn coerce_own() {
+    use ::core::{any::Any, fmt::Debug};
+
+    fn register<'f, F : FnOwn<(), Ret = usize> + 'f>(
+        registry: &mut Vec<OwnRef<'f, dyn FnOwn<(), Ret = usize> + 'f>>,
+        f: OwnRef<'f, F>,
+    )
+    {
+        registry.push(f.coerce());
+    }
+
+    let drops = &Drops::default();
+    let (a, b) = (String::from("a"), vec![drops.guard(), drops.guard()]);
+    let (mut sa, mut sb) = (slot(), slot());
+    let mut registry = vec![];
+    register(&mut registry, sa.holding(move || a.len()));
+    register(&mut registry, sb.holding(move || b.len()));
+    let mut registry = registry.into_iter();
+    assert_eq!(registry.next().unwrap().call_ownref_0(), 1);
+    drops.assert(0);
+    drop(registry);
+    drops.assert(2);
+
+    fn erase<'s, T : Debug + 's, D : traits::DropPolicy>(
+        o: OwnRef<'s, T, D>,
+    ) -> OwnRef<'s, dyn Debug + 's, D>
+    {
+        o.coerce()
+    }
+    {
+        let storage = pin::slot!();
+        let p = storage.holding([drops.guard(), drops.guard()]);
+        let p = unsafe { Pin::into_inner_unchecked(p) };
+        let p = unsafe { Pin::new_unchecked(erase(p)) };
+        assert_eq!(format!("{:?}", &*p), "[ScopeGuard { value: PhantomPinned }, ScopeGuard { value: PhantomPinned }]");
+        ::core::mem::forget(p);
+    } // <- drop flags still caught the leak.
+    drops.assert(4);
+
+    let s: OwnRef<'_, String> = own_ref!("…".into());
+    let any: OwnRef<'_, dyn Any> = s.coerce();
+    assert_eq!(any.downcast::<String>().ok().unwrap().deref_move(), "…");
+}
+
+#[test]
+fn fn_own_impl() {
+    use traits::FnOwnImpl;
+
+    /// A hand-rolled state machine, generic over its state.
+    struct Countdown<S> {
+        state: S,
+        remaining: usize,
+    }
+
+    impl<S> FnOwnImpl<(usize, )> for Countdown<S> {
+        type Ret = (S, usize);
+
+        fn call_owned(self, (extra, ): (usize, )) -> (S, usize) {
+            (self.state, self.remaining + extra)
+        }
+    }
+
+    let f: OwnRef<'_, dyn FnOwn<(usize, ), Ret = (i32, usize)>> = own_ref!(Countdown {
+        state: 42,
+        remaining: 2,
+    });
+    assert_eq!(f.call_ownref_1(1), (42, 3));
+    // Through `CoerceOwn`, and with the drop glue running, but not the call.
+    let drops = &Drops::default();
+    let mut storage = slot();
+    let f = storage.holding(Countdown { state: drops.guard(), remaining: 0 });
+    let f: OwnRef<'_, dyn FnOwn<(usize, ), Ret = (_, usize)>> = f.coerce();
+    drops.assert(0);
+    drop(f);
+    drops.assert(1);
+    // Nested, with the state being moved out rather than dropped.
+    let f: OwnRef<'_, dyn FnOwn<(usize, ), Ret = (_, usize)>> = own_ref!(Countdown {
+        state: drops.guard(),
+        remaining: 0,
+    });
+    let f: OwnRef<'_, OwnRef<'_, dyn FnOwn<(usize, ), Ret = (_, usize)>>> = own_ref!(f);
+    let (state, n) = f.call_ownref_1(0);
+    assert_eq!(n, 0);
+    drops.assert(1);
+    drop(state);
+    drops.assert(2);
+}
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn coerce_own_is_sealed() {
+    trait Local {}
+    impl Local for () {}
+    // Error, `u8 : Sealed<dyn Local>` does not hold (only `impl_coerce_own!`
+    // can make it so).
+    impl ::own_ref::traits::CoerceOwn<dyn Local> for u8 {
+        fn ඞcoerce(_: *mut u8) -> *mut dyn Local {
+            ::core::ptr::null_mut::<()>() as _
+        }
+    }
+}
+
+#[cfg(doctest)]
+#[apply(compile_fail!)]
+fn fn_own_is_sealed() {
+    struct Foo;
+    // Error, `Sealed<()>` is not implemented for `Foo`.
+    impl ::own_ref::traits::FnOwn<()> for Foo {
+        fn call_ownref(self, (): ()) {}
+    }
+}
@@ -43,7 +43,7 @@ use crate::OwnRef;
             T : Any,
             U : Any,
         {
-            let _checked_eq @ true = (&*self).type_id() == TypeId::of::<U>()
+            let _checked_eq @ true = (*self).type_id() == TypeId::of::<U>()
             else {
                 return Err(self);
             };
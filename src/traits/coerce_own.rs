@@ -0,0 +1,194 @@
+//! <code>[OwnRef]\<\'\_, T\>::[coerce][OwnRef::coerce]::\<dyn Trait\>()</code>,
+//! for generic `T`s.
+
+use ::core::{any::Any, fmt::Debug, future::Future, mem::ManuallyDrop};
+use crate::{traits::{DropPolicy, FnOwn}, OwnRef};
+
+pub trait Sealed<Dyn : ?Sized> {}
+
+/// `T : CoerceOwn<dyn Trait>` means that an <code>[OwnRef]\<\'\_, T\></code>
+/// can be unsized into an <code>[OwnRef]\<\'\_, dyn Trait\></code>, through
+/// [`OwnRef::coerce()`].
+///
+/// Contrary to [`unsize!`][crate::unsize!], this works with a generic `T`
+/// (much like `Box<T>` unsizing does, on stable Rust, thanks to the built-in
+/// `CoerceUnsized` magic we do not have access to).
+///
+/// The `dyn Trait`s for which this holds are those which have been registered
+/// through [`impl_coerce_own!`][crate::impl_coerce_own!], which this crate does
+/// for:
+///
+///   - <code>dyn [FnOwn]\<Args, Ret = R\></code>,
+///   - <code>dyn [Future]\<Output = R\></code>,
+///   - <code>dyn [Any]</code>,
+///   - <code>dyn [Debug]</code>,
+///
+/// as well as for their `+ Send` and/or `+ Sync` variants.
+///
+/// This trait is sealed: [`impl_coerce_own!`][crate::impl_coerce_own!] is the
+/// only way to extend it, since [`OwnRef::coerce()`] relies on it performing a
+/// proper unsizing.
+///
+/// ## Example
+///
+/// ```rust
+/// use ::own_ref::prelude::*;
+///
+/// fn register<'f, F : FnOwn<(), Ret = ()> + 'f>(
+///     registry: &mut Vec<OwnRef<'f, dyn FnOwn<(), Ret = ()> + 'f>>,
+///     f: OwnRef<'f, F>,
+/// )
+/// {
+///     registry.push(f.coerce());
+/// }
+///
+/// let mut registry = vec![];
+/// let (mut a, mut b) = (slot(), slot());
+/// register(&mut registry, a.holding(|| println!("a")));
+/// register(&mut registry, b.holding(|| println!("b")));
+/// for f in registry {
+///     f.call_ownref_0();
+/// }
+/// ```
+pub
+trait CoerceOwn<Dyn : ?Sized> : Sealed<Dyn> {
+    #[doc(hidden)] /** Not part of the public API */
+    fn ඞcoerce(ptr: *mut Self)
+      -> *mut Dyn
+    ;
+}
+
+impl<T, Dyn : ?Sized> Sealed<Dyn> for T
+where
+    Dyn : ඞCoerceOwnFrom<T>,
+{}
+
+impl<T, Dyn : ?Sized> CoerceOwn<Dyn> for T
+where
+    Dyn : ඞCoerceOwnFrom<T>,
+{
+    #[inline]
+    fn ඞcoerce(ptr: *mut T)
+      -> *mut Dyn
+    {
+        Dyn::ඞcoerce_from(ptr)
+    }
+}
+
+/// The `dyn Trait`-side of [`CoerceOwn`], so that [`impl_coerce_own!`] be
+/// usable downstream (`dyn LocalTrait` being a local type, contrary to a
+/// generic `T`).
+///
+/// [`impl_coerce_own!`]: crate::impl_coerce_own!
+#[doc(hidden)] /** Not part of the public API */ pub
+unsafe
+trait ඞCoerceOwnFrom<T> {
+    /// # Safety (for implementors)
+    ///
+    /// Must be an unsizing coërcion of `ptr` (which is thus expected to be
+    /// the whole body of this function).
+    fn ඞcoerce_from(ptr: *mut T)
+      -> *mut Self
+    ;
+}
+
+impl<'slot, T, D : DropPolicy> OwnRef<'slot, T, D> {
+    /// Performs an [`Unsize`][::core::marker::Unsize] coërcion of the owned
+    /// `T`, _e.g._, into a `dyn Trait`, even in generic code.
+    ///
+    /// See [`CoerceOwn`] for more info.
+    #[inline]
+    pub
+    fn coerce<Dyn : ?Sized + 'slot>(
+        self: OwnRef<'slot, T, D>,
+    ) -> OwnRef<'slot, Dyn, D>
+    where
+        T : CoerceOwn<Dyn>,
+    {
+        let (ptr, state, lt) = OwnRef::into_raw_parts(self);
+        let ptr: *mut Dyn = T::ඞcoerce(ptr.cast());
+        unsafe {
+            // Safety: this is just an unsizing of the `ptr` we got from
+            // `into_raw_parts()` (with the `ManuallyDrop` layer, which is
+            // `repr(transparent)`, having been temporarily peeled off): the
+            // only `CoerceOwn` impl is the one above, which delegates to the
+            // `unsafe` `ඞCoerceOwnFrom` trait.
+            OwnRef::from_raw_parts(ptr as *mut ManuallyDrop<Dyn>, state, lt)
+        }
+    }
+}
+
+/// Registers a `dyn Trait` for [`OwnRef::coerce()`] (_c.f._ [`CoerceOwn`]).
+///
+/// [`CoerceOwn`]: crate::traits::CoerceOwn
+///
+/// That is, `impl_coerce_own!(dyn Trait + Send)` makes any `T : Trait + Send`
+/// be <code>[CoerceOwn]\<dyn \'\_ + Trait + Send\></code>. Generic parameters,
+/// if any, are to be introduced upfront:
+/// `impl_coerce_own!(<R> dyn Trait<Output = R>)`.
+///
+/// Since this implements a trait for the `dyn Trait` type, `Trait` has to be
+/// local to the crate invoking this macro. Also, do not specify the lifetime
+/// of the `dyn` yourself: all of them are covered.
+///
+/// ## Example
+///
+/// ```rust
+/// use ::own_ref::prelude::*;
+///
+/// trait Shape {
+///     fn area(&self) -> f64;
+/// }
+///
+/// ::own_ref::impl_coerce_own!(dyn Shape);
+/// ::own_ref::impl_coerce_own!(dyn Shape + Send);
+///
+/// struct Square(f64);
+/// impl Shape for Square {
+///     fn area(&self) -> f64 { self.0 * self.0 }
+/// }
+///
+/// fn boxed_area<'s, S : Shape + Send + 's>(s: OwnRef<'s, S>) -> f64 {
+///     let s: OwnRef<'s, dyn Shape + Send> = s.coerce();
+///     s.area()
+/// }
+///
+/// assert_eq!(boxed_area(own_ref!(Square(2.))), 4.);
+/// ```
+#[macro_export]
+macro_rules! impl_coerce_own {(
+    $(< $($Generic:ident),* $(,)? >)?
+    dyn $($Trait:tt)+
+) => (
+    unsafe
+    impl<'ඞ, ඞT : 'ඞ + $($Trait)+ $($(, $Generic)*)?>
+        $crate::ඞ::ඞCoerceOwnFrom<ඞT>
+    for
+        dyn 'ඞ + $($Trait)+
+    {
+        #[inline]
+        fn ඞcoerce_from(ptr: *mut ඞT)
+          -> *mut Self
+        {
+            ptr
+        }
+    }
+)}
+
+macro_rules! impls {(
+    $( $(< $($Generic:ident),* >)? dyn $Trait:path ;)*
+) => (
+    $(
+        crate::impl_coerce_own!($(<$($Generic),*>)? dyn $Trait);
+        crate::impl_coerce_own!($(<$($Generic),*>)? dyn $Trait + Send);
+        crate::impl_coerce_own!($(<$($Generic),*>)? dyn $Trait + Sync);
+        crate::impl_coerce_own!($(<$($Generic),*>)? dyn $Trait + Send + Sync);
+    )*
+)}
+
+impls! {
+    <Args, R> dyn FnOwn<Args, Ret = R>;
+    <R> dyn Future<Output = R>;
+    dyn Any;
+    dyn Debug;
+}
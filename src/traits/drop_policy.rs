@@ -0,0 +1,140 @@
+//! <code>[OwnRef]\<\'slot, T, P\></code>, with `P` deciding what happens to
+//! the owned value when the [`OwnRef`] is dropped.
+
+#[cfg(doc)]
+use crate::{OwnRef, pin::DropFlags};
+
+/// What an <code>[OwnRef]\<\'slot, T, Self\></code> does, when dropped, with
+/// the `T` value it owns.
+///
+/// The two built-in policies are:
+///
+///   - [`DropFlags::No`], the default one, which merely drops the value in
+///     place;
+///
+///   - [`DropFlags::Yes`], which also clears the drop flag of the backing
+///     storage beforehand (_c.f._ [`OwnRef::from_raw_flagged()`]).
+///
+/// But other ones can be defined, so as to, for instance, recycle the value
+/// rather than dropping it, defer its dropping, or keep count of it. Such
+/// <code>[OwnRef]\<\'slot, T, P\></code>s are then to be constructed through
+/// [`OwnRef::from_raw_parts()`].
+///
+/// ## Example: a pool of buffers
+///
+/// ```rust
+/// use ::core::{cell::{Cell, UnsafeCell}, marker::PhantomData, mem::ManuallyDrop};
+/// use ::own_ref::{prelude::*, traits::{Covariant, DropPolicy}};
+///
+/// pub struct Pool<const N: usize> {
+///     buffers: [UnsafeCell<ManuallyDrop<Vec<u8>>>; N],
+///     in_use: [Cell<bool>; N],
+/// }
+///
+/// /// Returns the buffer to its pool (cleared, but with its capacity kept).
+/// pub struct ReturnToPool<'pool, const N: usize>(PhantomData<&'pool Pool<N>>);
+///
+/// impl<'pool, const N: usize> DropPolicy for ReturnToPool<'pool, N> {
+///     type State = (&'pool Pool<N>, usize);
+///     type Variance = Covariant;
+///
+///     unsafe fn drop_value<T : ?Sized>((pool, i): (&'pool Pool<N>, usize), value: *mut T) {
+///         // Safety: only ever instantiated with `T = Vec<u8>`, by `Pool::get()`.
+///         unsafe { &mut *value.cast::<Vec<u8>>() }.clear();
+///         pool.in_use[i].set(false);
+///     }
+/// }
+///
+/// impl<const N: usize> Pool<N> {
+///     pub fn get(&self) -> Option<OwnRef<'_, Vec<u8>, ReturnToPool<'_, N>>> {
+///         let i = self.in_use.iter().position(|in_use| !in_use.get())?;
+///         self.in_use[i].set(true);
+///         Some(unsafe {
+///             // Safety: the `i`-th buffer is not in use, and the policy
+///             // does not drop it, but marks it as available again instead.
+///             OwnRef::from_raw_parts(self.buffers[i].get(), (self, i), [])
+///         })
+///     }
+/// }
+///
+/// impl<const N: usize> Drop for Pool<N> {
+///     fn drop(&mut self) {
+///         for buffer in &mut self.buffers {
+///             unsafe { ManuallyDrop::drop(buffer.get_mut()) }
+///         }
+///     }
+/// }
+///
+/// let pool = Pool::<1> {
+///     buffers: [UnsafeCell::new(ManuallyDrop::new(Vec::with_capacity(1024)))],
+///     in_use: [Cell::new(false)],
+/// };
+/// let mut buffer = pool.get().unwrap();
+/// buffer.extend_from_slice(b"hello");
+/// assert!(pool.get().is_none());
+/// drop(buffer); // back to the pool
+/// let buffer = pool.get().unwrap();
+/// assert!(buffer.is_empty());
+/// assert!(buffer.capacity() >= 1024);
+/// ```
+pub
+trait DropPolicy {
+    /// What an <code>[OwnRef]\<\'\_, T, Self\></code> carries around for the
+    /// policy to operate: `()` for [`DropFlags::No`], the drop flag of the
+    /// backing storage for [`DropFlags::Yes`], or, say, a reference to the
+    /// pool the value is to be returned to.
+    type State;
+
+    /// Whether <code>[OwnRef]\<\'\_, T, Self\></code> may be covariant in
+    /// `T`, such as [`DropFlags::No`], or has to be [`Invariant`] instead,
+    /// such as [`DropFlags::Yes`].
+    ///
+    /// Covariance is only fine insofar the backing storage does not, itself,
+    /// drop the `T` as such (say, should the [`OwnRef`] be leaked), for it may
+    /// then be dropping some `T` with a shortened lifetime as if it were the
+    /// original one.
+    type Variance : VarianceKind;
+
+    /// Called by the [`Drop`] glue of an <code>[OwnRef]\<\'\_, T, Self\></code>.
+    ///
+    /// # Safety
+    ///
+    /// `value` points to a valid `T` which the callee now owns, and `state`
+    /// is the one the [`OwnRef`] was constructed with.
+    unsafe
+    fn drop_value<T : ?Sized>(state: Self::State, value: *mut T)
+    ;
+}
+
+/// [`DropPolicy::Variance`] of <code>[OwnRef]\<\'\_, T\></code>: covariant in
+/// `T`, much like a `Box<T>`.
+pub
+enum Covariant {}
+
+/// [`DropPolicy::Variance`] of
+/// <code>[OwnRef]\<\'\_, T, [DropFlags::Yes]\></code>: invariant in `T`.
+pub
+enum Invariant {}
+
+/// Either [`Covariant`] or [`Invariant`].
+pub
+trait VarianceKind : sealed::Sealed {
+    #[doc(hidden)] /** Not part of the public API */
+    type Of<T : ?Sized>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for Covariant {}
+
+impl VarianceKind for Covariant {
+    type Of<T : ?Sized> = ();
+}
+
+impl sealed::Sealed for Invariant {}
+
+impl VarianceKind for Invariant {
+    type Of<T : ?Sized> = fn(&T);
+}
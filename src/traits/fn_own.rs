@@ -16,6 +16,9 @@ pub trait Sealed<Args> {}
 ///
 /// > `   Box<    dyn '_ + Send… + Sync… + FnOnce(i32, u8)     -> String>`.
 ///
+/// It is implemented for closures and, more generally, for any
+/// [`FnOwnImpl`] type.
+///
 /// ## Example
 ///
 /// ```rust
@@ -60,6 +63,7 @@ macro_rules! call_ownref_N {(
             #[doc = crate::arities::max!()]
             /// arguments (some may have been hidden from the docs to keep them
             /// legible).
+            #[allow(clippy::too_many_arguments)]
             fn [< call_ownref$N >]<$($I),*>(
                 self,
                 $($I: $I),*
@@ -80,9 +84,78 @@ trait FnOwnRet<Args> : Sealed<Args> {
     type Ret;
 }
 
+/// The opt-in way for a (non-closure) type to be [`FnOwn`], and thus, for it
+/// to be usable as an <code>[OwnRef]\<\'\_, dyn [FnOwn]\<Args, Ret = …\>\></code>.
+///
+/// [`FnOwn`] itself is sealed, since its `dyn`-dispatch machinery relies on
+/// implementation details; it is, instead, implemented for every
+/// `FnOwnImpl<Args>` type (starting with every `FnOnce(…) -> Ret` closure).
+///
+/// `Args` is to be a tuple of the arguments, much like for [`FnOnce`].
+///
+/// ## Example
+///
+/// ```rust
+/// use ::own_ref::{prelude::*, traits::FnOwnImpl};
+///
+/// /// Sums the given numbers, up to `limit` of them.
+/// struct Summer {
+///     limit: usize,
+///     log: Vec<String>,
+/// }
+///
+/// impl FnOwnImpl<(&[i32], )> for Summer {
+///     type Ret = (i32, Vec<String>);
+///
+///     fn call_owned(mut self, (xs, ): (&[i32], )) -> (i32, Vec<String>) {
+///         let xs = &xs[.. xs.len().min(self.limit)];
+///         self.log.push(format!("summing {} numbers", xs.len()));
+///         (xs.iter().sum(), self.log)
+///     }
+/// }
+///
+/// let xs = [1, 2, 3];
+/// let f: OwnRef<'_, dyn FnOwn<(&[i32], ), Ret = (i32, Vec<String>)>> =
+///     own_ref!(Summer { limit: 2, log: vec![] })
+/// ;
+/// let (sum, log) = f.call_ownref_1(&xs[..]);
+/// assert_eq!(sum, 3);
+/// assert_eq!(log, ["summing 2 numbers"]);
+/// ```
+pub
+trait FnOwnImpl<Args> {
+    /// The return type of the call.
+    type Ret;
+
+    /// The moral equivalent of [`FnOnce::call_once`].
+    ///
+    /// Not to be confused with (but also, callable through)
+    /// [`FnOwn::call_ownref()`].
+    fn call_owned(self, args: Args)
+      -> Self::Ret
+    ;
+}
+
+impl<F : FnOwnImpl<Args>, Args> Sealed<Args> for F {}
+
+impl<F : FnOwnImpl<Args>, Args> FnOwnRet<Args> for F {
+    type Ret = F::Ret;
+}
+
+impl<F : FnOwnImpl<Args>, Args> FnOwn<Args> for F {
+    #[inline]
+    fn call_ownref(self, args: Args)
+      -> Self::Ret
+    {
+        self.call_owned(args)
+    }
+}
+
 #[doc(hidden)] /** Not part of the public API! */ pub
 trait FnOwnUnchecked<Args> : FnOwnRet<Args> {
-    // SAFETY(pub): NONE!
+    // SAFETY(pub): NONE! (and it cannot be implemented outside of this
+    // crate anyways, since the following blanket impl covers every `FnOwn`,
+    // and the latter is sealed.)
     // SAFETY(in crate): it must be sound for the callee to
     // transmute the `&'_ mut Self` to a `OwnRef<'_, Self>`.
     // This is true for `DropFlags::No`, for instance, when `Self`
@@ -129,31 +202,15 @@ macro_rules! impls {
         $($I:ident)*
     ) => (
         impl<F, Ret $(, $I)*>
-            Sealed<($($I, )*)>
-        for
-            F
-        where
-            F : FnOnce($($I),*) -> Ret,
-        {}
-
-        impl<F, Ret $(, $I)*>
-            FnOwnRet<($($I, )*)>
+            FnOwnImpl<($($I, )*)>
         for
             F
         where
             F : FnOnce($($I),*) -> Ret,
         {
             type Ret = Ret;
-        }
 
-        impl<F, Ret $(, $I)*>
-            FnOwn<($($I, )*)>
-        for
-            F
-        where
-            F : FnOnce($($I),*) -> Ret,
-        {
-            fn call_ownref(self, ($($I, )*): ($($I, )*))
+            fn call_owned(self, ($($I, )*): ($($I, )*))
               -> Self::Ret
             {
                 self($($I),*)
@@ -163,25 +220,13 @@ macro_rules! impls {
 } use impls;
 
 impl<'slot, Args, F : ?Sized + FnOwn<Args>>
-    Sealed<Args>
-for
-    OwnRef<'slot, F>
-{}
-
-impl<'slot, Args, F : ?Sized + FnOwn<Args>>
-    FnOwnRet<Args>
+    FnOwnImpl<Args>
 for
     OwnRef<'slot, F>
 {
     type Ret = F::Ret;
-}
 
-impl<'slot, Args, F : ?Sized + FnOwn<Args>>
-    FnOwn<Args>
-for
-    OwnRef<'slot, F>
-{
-    fn call_ownref(self, args: Args)
+    fn call_owned(self, args: Args)
       -> Self::Ret
     {
         unsafe {
@@ -4,8 +4,20 @@
 
 mod any;
 
-pub use fn_own::{FnOwn, FnOwnRet};
+pub use coerce_own::CoerceOwn;
+pub(in crate) mod coerce_own;
+
+pub use drop_policy::{Covariant, DropPolicy, Invariant, VarianceKind};
+mod drop_policy;
+
+pub use fn_own::{FnOwn, FnOwnImpl, FnOwnRet};
 mod fn_own;
 
+pub use own_project::OwnProject;
+mod own_project;
+
+#[doc(inline)]
+pub use crate::slot::{MaybeUninitExt, TupleSlots};
+
 #[doc(inline)]
-pub use crate::slot::MaybeUninitExt;
+pub use crate::dyn_slot::{Alignment, ValidAlignment};
@@ -0,0 +1,335 @@
+//! <code>[OwnRef]\<\'slot, MyEnum\>::[project][OwnRef::project]\(\) -> MyEnumOwn\<\'slot\></code>
+
+use crate::OwnRef;
+
+/// Types which can be matched on _by ownership_ from behind an [`OwnRef`],
+/// with each of their fields being bound as an [`OwnRef`] in place (_i.e._,
+/// without moving the payload out of the original backing storage).
+///
+/// This is the natural completion of [`OwnRef`] as "`Box` without the heap".
+///
+/// Rather than implementing it manually, you'll want to use the
+/// [`OwnProject!`][crate::OwnProject!] derive.
+pub
+trait OwnProject {
+    /// The projection of `Self`, with [`OwnRef`] fields.
+    type Projected<'slot>
+    where
+        Self : 'slot,
+    ;
+
+    /// Does not move the payload; see [`OwnRef::project()`].
+    fn project<'slot>(
+        this: OwnRef<'slot, Self>,
+    ) -> Self::Projected<'slot>
+    ;
+}
+
+impl<'slot, T : OwnProject> OwnRef<'slot, T> {
+    /// Projects an owned `T` into its [`OwnProject::Projected`] form, so as to
+    /// be able to `match` on it, binding each field as an [`OwnRef`] in place.
+    ///
+    /// See [`OwnProject!`][crate::OwnProject!] for an example.
+    #[inline]
+    pub
+    fn project(
+        self: OwnRef<'slot, T>,
+    ) -> T::Projected<'slot>
+    {
+        T::project(self)
+    }
+}
+
+/// Derives [`OwnProject`] for an `enum` (to be used with
+/// [`macro_rules_attribute`](https://docs.rs/macro_rules_attribute)'s
+/// `#[derive(…!)]`).
+///
+/// A `MyEnum` gets a `MyEnumOwn<'slot, …>` projection `enum` emitted alongside
+/// it, with the same variants, but for their fields being [`OwnRef`]s (when
+/// none of the variants has fields, `MyEnumOwn` has no `'slot` parameter).
+///
+/// ```rust
+/// use ::macro_rules_attribute::derive;
+/// use ::own_ref::{prelude::*, OwnProject};
+///
+/// #[derive(OwnProject!)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Polygon(String, Vec<(f64, f64)>),
+///     Empty,
+/// }
+///
+/// fn name(shape: OwnRef<'_, Shape>) -> String {
+///     match shape.project() {
+///         ShapeOwn::Circle { radius } => format!("circle of radius {}", *radius),
+///         ShapeOwn::Polygon(name, vertices) => {
+///             drop(vertices); // dropped in place.
+///             name.deref_move()
+///         },
+///         ShapeOwn::Empty => "nothing".into(),
+///     }
+/// }
+///
+/// assert_eq!(name(own_ref!(Shape::Polygon("square".into(), vec![]))), "square");
+/// ```
+///
+/// ## Generics
+///
+/// Lifetime and type parameters are supported, but for the type parameters'
+/// bounds needing to be expressed through a single path each (extra bounds
+/// may be expressed in the `where` clause, one path per clause).
+///
+/// Tuple variants are limited to 12 fields.
+///
+/// ## `Drop`
+///
+/// Much like with moving out of the fields of an `enum` in plain Rust, the
+/// `enum` must not implement [`Drop`] (which this derive checks), since
+/// projecting it takes it apart.
+#[macro_export]
+macro_rules! OwnProject {(
+    $(#[$attr:meta])*
+    $pub:vis
+    enum $Name:ident
+    $(<
+        $( $lt:lifetime ),* $(,)?
+        $( $T:ident $(: $Bound:path)? ),* $(,)?
+    >)?
+    $(where
+        $( $WcTy:ty : $WcBound:path ),* $(,)?
+    )?
+    {
+        $($variants:tt)*
+    }
+) => (
+    $crate::OwnProject! {
+        @variants
+        [$pub $Name]
+        [$( $($lt,)* $($T,)* )?]
+        [$( $($lt,)* $($T $(: $Bound)?,)* )?]
+        [$( $($WcTy : $WcBound,)* )?]
+        [/* defs */]
+        [/* arms */]
+        $($variants)*
+    }
+);
+
+// Braced variant.
+(
+    @variants $pub_Name:tt $params:tt $generics:tt $wc:tt
+    [$($defs:tt)*]
+    [$($arms:tt)*]
+    $(#[$vattr:meta])*
+    $Variant:ident {
+        $( $(#[$fattr:meta])* $field:ident : $FTy:ty ),* $(,)?
+    } $(= $disc:expr)?
+    $(, $($rest:tt)*)?
+) => (
+    $crate::OwnProject! {
+        @variants $pub_Name $params $generics $wc
+        [$($defs)*
+            $(#[$vattr])*
+            $Variant {
+                $( $(#[$fattr])* $field : $crate::OwnRef<'slot, $FTy>, )*
+            },
+        ]
+        [$($arms)*
+            [$Variant $( $field : $field )*]
+        ]
+        $($($rest)*)?
+    }
+);
+
+// Tuple variant.
+(
+    @variants $pub_Name:tt $params:tt $generics:tt $wc:tt
+    [$($defs:tt)*]
+    [$($arms:tt)*]
+    $(#[$vattr:meta])*
+    $Variant:ident (
+        $( $(#[$fattr:meta])* $FTy:ty ),* $(,)?
+    ) $(= $disc:expr)?
+    $(, $($rest:tt)*)?
+) => (
+    $crate::OwnProject! {
+        @tuple-fields
+        [$($FTy)*]
+        [
+            0 _0 1 _1 2 _2 3 _3 4 _4 5 _5
+            6 _6 7 _7 8 _8 9 _9 10 _10 11 _11
+        ]
+        []
+        @variants $pub_Name $params $generics $wc
+        [$($defs)*
+            $(#[$vattr])*
+            $Variant (
+                $( $(#[$fattr])* $crate::OwnRef<'slot, $FTy>, )*
+            ),
+        ]
+        [$($arms)*]
+        $Variant
+        $($($rest)*)?
+    }
+);
+
+// Unit variant.
+(
+    @variants $pub_Name:tt $params:tt $generics:tt $wc:tt
+    [$($defs:tt)*]
+    [$($arms:tt)*]
+    $(#[$vattr:meta])*
+    $Variant:ident $(= $disc:expr)?
+    $(, $($rest:tt)*)?
+) => (
+    $crate::OwnProject! {
+        @variants $pub_Name $params $generics $wc
+        [$($defs)*
+            $(#[$vattr])*
+            $Variant,
+        ]
+        [$($arms)*
+            [$Variant]
+        ]
+        $($($rest)*)?
+    }
+);
+
+// Names the fields of a tuple variant, so as to be able to bind them.
+(
+    @tuple-fields
+    [$FTy:tt $($FTys:tt)*]
+    [$i:tt $name:ident $($pool:tt)*]
+    [$($fields:tt)*]
+    $($rest:tt)*
+) => (
+    $crate::OwnProject! {
+        @tuple-fields
+        [$($FTys)*]
+        [$($pool)*]
+        [$($fields)* $i : $name]
+        $($rest)*
+    }
+);
+
+(
+    @tuple-fields
+    []
+    $pool:tt
+    [$($fields:tt)*]
+    @variants $pub_Name:tt $params:tt $generics:tt $wc:tt
+    $defs:tt
+    [$($arms:tt)*]
+    $Variant:ident
+    $($rest:tt)*
+) => (
+    $crate::OwnProject! {
+        @variants $pub_Name $params $generics $wc
+        $defs
+        [$($arms)*
+            [$Variant $($fields)*]
+        ]
+        $($rest)*
+    }
+);
+
+// Done munching the variants, none of which has fields: `'slot` would then be
+// unused, so the projection `enum` does without it.
+(
+    @variants $pub_Name:tt $params:tt $generics:tt $wc:tt
+    $defs:tt
+    [$( [$Variant:ident] )*]
+    /* no more variants */
+) => (
+    $crate::OwnProject! {
+        @emit $pub_Name [] $params $generics $wc
+        $defs
+        [$( [$Variant] )*]
+    }
+);
+
+(
+    @variants $pub_Name:tt $params:tt $generics:tt $wc:tt
+    $defs:tt
+    $arms:tt
+    /* no more variants */
+) => (
+    $crate::OwnProject! {
+        @emit $pub_Name ['slot,] $params $generics $wc
+        $defs
+        $arms
+    }
+);
+
+// Emit the projection enum, and the impl.
+(
+    @emit
+    [$pub:vis $Name:ident]
+    [$($slot:tt)*]
+    [$($params:tt)*]
+    [$($generics:tt)*]
+    [$($wc:tt)*]
+    [$($defs:tt)*]
+    [$(
+        [$Variant:ident $( $key:tt : $name:ident )*]
+    )*]
+) => ($crate::ඞ::paste! {
+    #[allow(nonstandard_style)]
+    $pub
+    enum [<$Name Own>]<$($slot)* $($generics)*>
+    where
+        $($wc)*
+    {
+        $($defs)*
+    }
+
+    // Emulate a `!Drop` bound on `Self`: taking it apart would otherwise skip
+    // its drop glue (much like moving out of its fields would, _c.f._ E0509).
+    const _: () = {
+        #[allow(dead_code)]
+        trait MustNotImplDrop {}
+        #[allow(drop_bounds)]
+        impl<T : ::core::ops::Drop> MustNotImplDrop for T {}
+        impl<$($generics)*> MustNotImplDrop for $Name<$($params)*>
+        where
+            $($wc)*
+        {}
+    };
+
+    impl<$($generics)*> $crate::traits::OwnProject for $Name<$($params)*>
+    where
+        $($wc)*
+    {
+        type Projected<'slot> = [<$Name Own>]<$($slot)* $($params)*>
+        where
+            Self : 'slot,
+        ;
+
+        #[allow(unused_variables)]
+        fn project<'slot>(
+            this: $crate::OwnRef<'slot, Self>,
+        ) -> Self::Projected<'slot>
+        {
+            let (ptr, lt) = $crate::OwnRef::into_raw(this);
+            match *unsafe {
+                // Safety: we have disarmed `this`, so as to be transferring
+                // ownership of the `enum` as a whole to its (only) active
+                // variant's fields.
+                &mut *ptr.cast::<Self>()
+            } {
+                $(
+                    Self::$Variant { $( $key : ref mut $name ),* } => {
+                        [<$Name Own>]::$Variant { $(
+                            $key : unsafe {
+                                // Safety: see above.
+                                $crate::OwnRef::from_raw(
+                                    ::core::ptr::addr_of_mut!(*$name).cast(),
+                                    lt,
+                                )
+                            },
+                        )* }
+                    },
+                )*
+            }
+        }
+    }
+})}
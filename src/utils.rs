@@ -38,3 +38,42 @@ macro_rules! match_cfg {
         }                               )?
     );
 } pub(in crate) use match_cfg;
+
+/// Replaces the address (and provenance) of a potentially wide pointer with
+/// that of `data`, whilst keeping its metadata (slice length, vtable, …).
+///
+/// With the `nightly` Cargo feature, this is just `ptr.with_metadata_of()`.
+///
+/// On stable Rust, the metadata of `ptr` cannot be reattached to another
+/// address without assuming the layout of wide pointers: re-offsetting `ptr`
+/// itself (_e.g._, through `.wrapping_byte_offset()`) would keep the
+/// provenance of `ptr`, whose allocation is not that of `data`, hence UB when
+/// dereferenced. So we rely on the data pointer being laid out first within
+/// wide pointers (as it is, and always has been, with `rustc`), and check it.
+pub(in crate)
+fn with_data_ptr<T : ?Sized>(
+    ptr: *mut T,
+    data: *mut u8,
+) -> *mut T
+{
+    match_cfg! {
+        feature = "nightly" => {
+            data.with_metadata_of(ptr)
+        },
+        _ => {{
+            let mut ptr = ptr;
+            unsafe {
+                // Safety: the data pointer is laid out first within wide
+                // pointers (and is the whole of thin ones), as checked below,
+                // and we are writing a proper pointer (including its
+                // provenance) to it.
+                <*mut *mut T>::cast::<*mut u8>(&mut ptr).write(data);
+            }
+            assert_eq!(
+                ptr.cast::<u8>(), data,
+                "unsupported wide pointer layout: the data pointer is not first",
+            );
+            ptr
+        }},
+    }
+}